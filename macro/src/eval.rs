@@ -0,0 +1,564 @@
+//! Evaluation of the constant expressions used as the bounds and discriminants of enums.
+//!
+//! Expressions are evaluated in the primitive integer type of the enum like the compiler would,
+//! so every intermediate value must fit in that type.
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use proc_macro2::Literal;
+use syn::{BinOp, Error, Expr, ExprBinary, ExprGroup, ExprLit, ExprParen, ExprPath, ExprUnary};
+use syn::{Lit, Path, PathArguments, UnOp};
+
+/// A primitive integer type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IntType {
+    signed: bool,
+    bits: u32,
+}
+
+impl IntType {
+    pub(crate) const I128: Self = Self {
+        signed: true,
+        bits: 128,
+    };
+    pub(crate) const U128: Self = Self {
+        signed: false,
+        bits: 128,
+    };
+
+    /// Gets the primitive integer type named by a path, such as `u8` or `core::primitive::u8`.
+    /// `usize` and `isize` are assumed to be 64 bits wide.
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        Self::from_names(&segment_names(path)?, path.leading_colon.is_some())
+    }
+
+    /// Gets the primitive integer type named by the segments of a path, which are either just the
+    /// name of the type or prefixed by `core::primitive` or `std::primitive`.
+    fn from_names(names: &[String], leading_colon: bool) -> Option<Self> {
+        match names {
+            [name] if !leading_colon => Self::from_name(name),
+            [krate, primitive, name]
+                if (krate == "core" || krate == "std") && primitive == "primitive" =>
+            {
+                Self::from_name(name)
+            }
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let (signed, bits) = match name {
+            "u8" => (false, 8),
+            "u16" => (false, 16),
+            "u32" => (false, 32),
+            "u64" | "usize" => (false, 64),
+            "u128" => (false, 128),
+            "i8" => (true, 8),
+            "i16" => (true, 16),
+            "i32" => (true, 32),
+            "i64" | "isize" => (true, 64),
+            "i128" => (true, 128),
+            _ => return None,
+        };
+        Some(Self { signed, bits })
+    }
+
+    fn contains(self, value: Int) -> bool {
+        match value {
+            Int::Signed(value) => {
+                let shift = 128 - self.bits;
+                value << shift >> shift == value
+            }
+            Int::Unsigned(value) => value & !self.mask() == 0,
+        }
+    }
+
+    /// Gets the smallest type that can hold every value of a range, preferring unsigned types.
+    pub(crate) fn smallest(range: &RangeInclusive<Int>) -> Self {
+        let signed = range.start().is_negative();
+        [8, 16, 32, 64, 128]
+            .iter()
+            .map(|&bits| Self { signed, bits })
+            .find(|ty| {
+                // Values are converted as they may have been evaluated in a type of the other
+                // signedness.
+                let contains = |value: Int| value.cast(*ty).is_some();
+                contains(*range.start()) && contains(*range.end())
+            })
+            .expect("the range was evaluated in a 128-bit type")
+    }
+
+    pub(crate) fn is_signed(self) -> bool {
+        self.signed
+    }
+
+    pub(crate) fn min(self) -> Int {
+        if self.signed {
+            Int::Signed(-1 << (self.bits - 1))
+        } else {
+            Int::Unsigned(0)
+        }
+    }
+
+    pub(crate) fn max(self) -> Int {
+        if self.signed {
+            Int::Signed((self.mask() >> 1) as i128)
+        } else {
+            Int::Unsigned(self.mask())
+        }
+    }
+
+    /// All the bits of the type set.
+    fn mask(self) -> u128 {
+        u128::MAX >> (128 - self.bits)
+    }
+}
+
+impl Display for IntType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", if self.signed { 'i' } else { 'u' }, self.bits)
+    }
+}
+
+/// A value of a primitive integer type. Values of different signedness are never compared.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) enum Int {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl Int {
+    pub(crate) fn is_zero(self) -> bool {
+        matches!(self, Self::Signed(0) | Self::Unsigned(0))
+    }
+
+    pub(crate) fn is_negative(self) -> bool {
+        matches!(self, Self::Signed(value) if value < 0)
+    }
+
+    pub(crate) fn unsigned_abs(self) -> u128 {
+        match self {
+            Self::Signed(value) => value.unsigned_abs(),
+            Self::Unsigned(value) => value,
+        }
+    }
+
+    /// Gets the number of values from `start` to `self`, which must not be less than `start`.
+    pub(crate) fn offset_from(self, start: Self) -> u128 {
+        match (self, start) {
+            (Self::Signed(value), Self::Signed(start)) => value.wrapping_sub(start) as u128,
+            (Self::Unsigned(value), Self::Unsigned(start)) => value - start,
+            _ => unreachable!("mixed signedness"),
+        }
+    }
+
+    /// Adds an offset to the value, wrapping around on overflow.
+    pub(crate) fn add_offset(self, offset: u128) -> Self {
+        match self {
+            Self::Signed(value) => Self::Signed(value.wrapping_add(offset as i128)),
+            Self::Unsigned(value) => Self::Unsigned(value.wrapping_add(offset)),
+        }
+    }
+
+    /// Gets the value one greater than this one, if it is in the range of the type.
+    pub(crate) fn successor(self, ty: IntType) -> Option<Self> {
+        let next = match self {
+            Self::Signed(value) => Self::Signed(value.checked_add(1)?),
+            Self::Unsigned(value) => Self::Unsigned(value.checked_add(1)?),
+        };
+        Some(next).filter(|&next| ty.contains(next))
+    }
+
+    /// Creates an unsuffixed literal of the value.
+    pub(crate) fn literal(self) -> Literal {
+        match self {
+            Self::Signed(value) => Literal::i128_unsuffixed(value),
+            Self::Unsigned(value) => Literal::u128_unsuffixed(value),
+        }
+    }
+
+    /// Converts the value to another type, if it is in its range.
+    fn cast(self, ty: IntType) -> Option<Self> {
+        let value = match (self, ty.signed) {
+            (Self::Signed(value), false) => Self::Unsigned(u128::try_from(value).ok()?),
+            (Self::Unsigned(value), true) => Self::Signed(i128::try_from(value).ok()?),
+            (value, _) => value,
+        };
+        Some(value).filter(|&value| ty.contains(value))
+    }
+
+    fn apply(
+        self,
+        rhs: Self,
+        signed: fn(i128, i128) -> Option<i128>,
+        unsigned: fn(u128, u128) -> Option<u128>,
+    ) -> Option<Self> {
+        match (self, rhs) {
+            (Self::Signed(lhs), Self::Signed(rhs)) => signed(lhs, rhs).map(Self::Signed),
+            (Self::Unsigned(lhs), Self::Unsigned(rhs)) => unsigned(lhs, rhs).map(Self::Unsigned),
+            _ => unreachable!("mixed signedness"),
+        }
+    }
+}
+
+impl Display for Int {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(value) => value.fmt(f),
+            Self::Unsigned(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Evaluates a constant expression in the given type.
+pub(crate) fn eval_expr(expr: &Expr, ty: IntType) -> syn::Result<Int> {
+    let overflow = || {
+        Error::new_spanned(
+            expr,
+            format!("this expression overflows the range of `{}`", ty),
+        )
+    };
+
+    let value = match expr {
+        Expr::Lit(ExprLit { lit, .. }) => eval_lit(lit, false, ty)?,
+        Expr::Unary(ExprUnary { op, expr, .. }) => match op {
+            UnOp::Neg(_) if !ty.signed => {
+                return Err(Error::new_spanned(
+                    op,
+                    format!("cannot negate a value of the unsigned type `{}`", ty),
+                ));
+            }
+            // Negated literals are evaluated directly so that the minimum value can be written.
+            UnOp::Neg(_) => match &**expr {
+                Expr::Lit(ExprLit { lit, .. }) => eval_lit(lit, true, ty)?,
+                expr => match eval_expr(expr, ty)? {
+                    Int::Signed(value) => Int::Signed(value.checked_neg().ok_or_else(overflow)?),
+                    Int::Unsigned(_) => unreachable!("unsigned value in a signed type"),
+                },
+            },
+            UnOp::Not(_) => match eval_expr(expr, ty)? {
+                Int::Signed(value) => Int::Signed(!value),
+                Int::Unsigned(value) => Int::Unsigned(!value & ty.mask()),
+            },
+            _ => {
+                return Err(Error::new_spanned(op, "unary operator must be ! or -"));
+            }
+        },
+        Expr::Binary(ExprBinary {
+            left, op, right, ..
+        }) => {
+            let lhs = eval_expr(left, ty)?;
+            let rhs = eval_expr(right, ty)?;
+            if matches!(op, BinOp::Div(_) | BinOp::Rem(_)) && rhs.is_zero() {
+                return Err(Error::new_spanned(expr, "attempt to divide by zero"));
+            }
+            let value = match op {
+                BinOp::Add(_) => lhs.apply(rhs, i128::checked_add, u128::checked_add),
+                BinOp::Sub(_) => lhs.apply(rhs, i128::checked_sub, u128::checked_sub),
+                BinOp::Mul(_) => lhs.apply(rhs, i128::checked_mul, u128::checked_mul),
+                BinOp::Div(_) => lhs.apply(rhs, i128::checked_div, u128::checked_div),
+                BinOp::Rem(_) => lhs.apply(rhs, i128::checked_rem, u128::checked_rem),
+                BinOp::BitXor(_) => lhs.apply(rhs, |l, r| Some(l ^ r), |l, r| Some(l ^ r)),
+                BinOp::BitAnd(_) => lhs.apply(rhs, |l, r| Some(l & r), |l, r| Some(l & r)),
+                BinOp::BitOr(_) => lhs.apply(rhs, |l, r| Some(l | r), |l, r| Some(l | r)),
+                BinOp::Shl(_) | BinOp::Shr(_) => {
+                    let shift = u32::try_from(rhs.unsigned_abs())
+                        .ok()
+                        .filter(|&shift| !rhs.is_negative() && shift < ty.bits)
+                        .ok_or_else(|| {
+                            Error::new_spanned(
+                                right,
+                                format!(
+                                    "shift amount must be less than the {} bits of `{}`",
+                                    ty.bits, ty,
+                                ),
+                            )
+                        })?;
+                    Some(match (op, lhs) {
+                        // Bits shifted out to the left are discarded, like the compiler does.
+                        (BinOp::Shl(_), Int::Signed(value)) => {
+                            let unused = 128 - ty.bits;
+                            Int::Signed(value << shift << unused >> unused)
+                        }
+                        (BinOp::Shl(_), Int::Unsigned(value)) => {
+                            Int::Unsigned(value << shift & ty.mask())
+                        }
+                        (_, Int::Signed(value)) => Int::Signed(value >> shift),
+                        (_, Int::Unsigned(value)) => Int::Unsigned(value >> shift),
+                    })
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        op,
+                        "operator not supported in this context",
+                    ));
+                }
+            };
+            value.ok_or_else(overflow)?
+        }
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => eval_path(path)?.cast(ty).ok_or_else(overflow)?,
+        Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+            eval_expr(expr, ty)?
+        }
+        _ => return Err(Error::new_spanned(expr, "expected simple expression")),
+    };
+
+    if ty.contains(value) {
+        Ok(value)
+    } else {
+        Err(overflow())
+    }
+}
+
+/// Checks whether an expression is made only of the literals, constants and operators that
+/// [`eval_expr`] supports, so that any error it gives is an error in the expression itself.
+pub(crate) fn is_evaluable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(_), ..
+        }) => true,
+        Expr::Unary(ExprUnary { op, expr, .. }) => {
+            matches!(op, UnOp::Neg(_) | UnOp::Not(_)) && is_evaluable(expr)
+        }
+        Expr::Binary(ExprBinary {
+            left, op, right, ..
+        }) => {
+            matches!(
+                op,
+                BinOp::Add(_)
+                    | BinOp::Sub(_)
+                    | BinOp::Mul(_)
+                    | BinOp::Div(_)
+                    | BinOp::Rem(_)
+                    | BinOp::BitXor(_)
+                    | BinOp::BitAnd(_)
+                    | BinOp::BitOr(_)
+                    | BinOp::Shl(_)
+                    | BinOp::Shr(_)
+            ) && is_evaluable(left)
+                && is_evaluable(right)
+        }
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => eval_path(path).is_ok(),
+        Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+            is_evaluable(expr)
+        }
+        _ => false,
+    }
+}
+
+/// Evaluates a path to a constant of a primitive integer type, such as `u8::MAX` or
+/// `core::primitive::u32::BITS`.
+fn eval_path(path: &Path) -> syn::Result<Int> {
+    let constant = segment_names(path).and_then(|names| {
+        let (name, ty) = names.split_last()?;
+        let ty = IntType::from_names(ty, path.leading_colon.is_some())?;
+        Some((ty, name.clone()))
+    });
+    match constant.as_ref().map(|(ty, name)| (*ty, &**name)) {
+        Some((ty, "MIN")) => Ok(ty.min()),
+        Some((ty, "MAX")) => Ok(ty.max()),
+        Some((ty, "BITS")) => Ok(Int::Unsigned(u128::from(ty.bits))),
+        _ => Err(Error::new_spanned(
+            path,
+            "expected `MIN`, `MAX` or `BITS` of a primitive integer type",
+        )),
+    }
+}
+
+/// Checks whether a path names one of the given primitive types, which can be written in the same
+/// forms as the primitive integer types.
+pub(crate) fn is_primitive(path: &Path, names: &[&str]) -> bool {
+    let name = match segment_names(path).as_deref() {
+        Some([name]) if path.leading_colon.is_none() => name.clone(),
+        Some([krate, primitive, name])
+            if (krate == "core" || krate == "std") && primitive == "primitive" =>
+        {
+            name.clone()
+        }
+        _ => return false,
+    };
+    names.contains(&&*name)
+}
+
+/// Gets the names of the segments of a path, if none of them have generic arguments.
+fn segment_names(path: &Path) -> Option<Vec<String>> {
+    path.segments
+        .iter()
+        .map(|segment| match segment.arguments {
+            PathArguments::None => Some(segment.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Evaluates an integer literal, which is negated if `negative` is set.
+fn eval_lit(lit: &Lit, negative: bool, ty: IntType) -> syn::Result<Int> {
+    let int = match lit {
+        Lit::Int(int) => int,
+        _ => return Err(Error::new_spanned(lit, "literal must be integer")),
+    };
+    let out_of_range = || Error::new_spanned(lit, format!("literal out of range for `{}`", ty));
+    let magnitude: u128 = int.base10_parse().map_err(|_| out_of_range())?;
+    let value = if !ty.signed {
+        Int::Unsigned(magnitude)
+    } else if negative {
+        Int::Signed(
+            0_i128
+                .checked_sub_unsigned(magnitude)
+                .ok_or_else(out_of_range)?,
+        )
+    } else {
+        Int::Signed(i128::try_from(magnitude).map_err(|_| out_of_range())?)
+    };
+    if ty.contains(value) {
+        Ok(value)
+    } else {
+        Err(out_of_range())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    fn eval(expr: Expr, ty: Path) -> syn::Result<Int> {
+        eval_expr(&expr, IntType::from_path(&ty).unwrap())
+    }
+
+    #[test]
+    fn test_wide_values() {
+        let u128_max = Int::Unsigned(u128::MAX);
+        assert_eq!(
+            eval(
+                parse_quote!(340282366920938463463374607431768211455),
+                parse_quote!(u128)
+            )
+            .unwrap(),
+            u128_max
+        );
+        assert_eq!(
+            eval(parse_quote!(!0), parse_quote!(u128)).unwrap(),
+            u128_max
+        );
+        assert_eq!(
+            eval(
+                parse_quote!(-170141183460469231731687303715884105728),
+                parse_quote!(i128)
+            )
+            .unwrap(),
+            Int::Signed(i128::MIN)
+        );
+        assert_eq!(
+            eval(parse_quote!(-128), parse_quote!(i8)).unwrap(),
+            Int::Signed(-128)
+        );
+        assert_eq!(
+            eval(parse_quote!(!0), parse_quote!(u8)).unwrap(),
+            Int::Unsigned(255)
+        );
+        assert_eq!(
+            eval(parse_quote!(!0), parse_quote!(i8)).unwrap(),
+            Int::Signed(-1)
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        assert_eq!(
+            eval(parse_quote!(1 << 127), parse_quote!(u128)).unwrap(),
+            Int::Unsigned(1 << 127)
+        );
+        assert_eq!(
+            eval(parse_quote!(1 << 7), parse_quote!(i8)).unwrap(),
+            Int::Signed(-128)
+        );
+        assert_eq!(
+            eval(parse_quote!(0xFF << 4), parse_quote!(u8)).unwrap(),
+            Int::Unsigned(0xF0)
+        );
+        assert_eq!(
+            eval(parse_quote!(-16 >> 2), parse_quote!(i8)).unwrap(),
+            Int::Signed(-4)
+        );
+        assert_eq!(
+            eval(parse_quote!(0x80 >> 7), parse_quote!(u8)).unwrap(),
+            Int::Unsigned(1)
+        );
+        assert!(eval(parse_quote!(1 << 8), parse_quote!(u8)).is_err());
+        assert!(eval(parse_quote!(1 << -1), parse_quote!(i8)).is_err());
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            eval(parse_quote!(u8::MAX), parse_quote!(u8)).unwrap(),
+            Int::Unsigned(255)
+        );
+        assert_eq!(
+            eval(parse_quote!(i8::MIN), parse_quote!(i16)).unwrap(),
+            Int::Signed(-128)
+        );
+        assert_eq!(
+            eval(parse_quote!(core::primitive::i16::MIN), parse_quote!(i16)).unwrap(),
+            Int::Signed(i16::MIN.into()),
+        );
+        assert_eq!(
+            eval(
+                parse_quote!(::std::primitive::u128::MAX),
+                parse_quote!(u128)
+            )
+            .unwrap(),
+            Int::Unsigned(u128::MAX),
+        );
+        assert_eq!(
+            eval(parse_quote!(i128::MAX), parse_quote!(i128)).unwrap(),
+            Int::Signed(i128::MAX),
+        );
+        assert_eq!(
+            eval(parse_quote!(u8::MAX - 1), parse_quote!(u32)).unwrap(),
+            Int::Unsigned(254),
+        );
+        assert_eq!(
+            eval(parse_quote!(1 << u8::BITS), parse_quote!(u16)).unwrap(),
+            Int::Unsigned(256),
+        );
+
+        let errors: [(Expr, Path); 5] = [
+            (parse_quote!(i8::MIN), parse_quote!(u8)),
+            (parse_quote!(u16::MAX), parse_quote!(i16)),
+            (parse_quote!(u8::ZERO), parse_quote!(u8)),
+            (parse_quote!(::u8::MAX), parse_quote!(u8)),
+            (parse_quote!(foo::u8::MAX), parse_quote!(u8)),
+        ];
+        for (expr, ty) in errors.iter().cloned() {
+            assert!(eval(expr, ty).is_err());
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors: [(Expr, Path); 8] = [
+            (parse_quote!(256), parse_quote!(u8)),
+            (parse_quote!(128), parse_quote!(i8)),
+            (parse_quote!(-129), parse_quote!(i8)),
+            (parse_quote!(-1), parse_quote!(u64)),
+            (parse_quote!(200 + 100 - 100), parse_quote!(u8)),
+            (parse_quote!(-(-128)), parse_quote!(i8)),
+            (parse_quote!(1 / (1 - 1)), parse_quote!(i32)),
+            (parse_quote!(5 % 0), parse_quote!(u32)),
+        ];
+        for (expr, ty) in errors.iter().cloned() {
+            assert!(eval(expr, ty).is_err());
+        }
+        let value = eval(parse_quote!(1 + 255 * 2), parse_quote!(u16)).unwrap();
+        assert_eq!(value, Int::Unsigned(511));
+    }
+}
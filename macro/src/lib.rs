@@ -1,32 +1,43 @@
 //! A macro for generating bounded integer structs and enums.
 
-use std::cmp::Ordering;
 use std::iter;
 use std::ops::RangeInclusive;
 
-use proc_macro2::{Ident, Literal, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::parse::{self, Parse, ParseStream};
-use syn::{braced, parse_macro_input, token::Brace, Token};
-use syn::{Attribute, Error, Expr, Path, PathSegment, Visibility};
-use syn::{BinOp, ExprBinary, ExprRange, ExprUnary, RangeLimits, UnOp};
-use syn::{ExprGroup, ExprParen};
-use syn::{ExprLit, Lit};
+use syn::ext::IdentExt;
+use syn::parse::{self, Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{braced, parenthesized, parse_macro_input, parse_quote, token::Brace, Token};
+use syn::{AttrStyle, Attribute, Error, Expr, Path, PathSegment, Visibility};
+use syn::{Data, DataStruct, DataUnion, DeriveInput, Fields, Item, Type, TypePath};
+use syn::{ExprLit, ExprPath, ExprRange, RangeLimits};
+use syn::{Lit, LitBool, LitInt, LitStr};
+
+use crate::eval::{eval_expr, is_evaluable, is_primitive, Int, IntType};
+use crate::set::{Part, ValueSet};
+
+mod eval;
+mod set;
 
 /// Generate a bounded integer type.
 ///
-/// It takes in single struct or enum, with the content being any range expression, which can be
-/// inclusive or not. The attributes and visibility (e.g. `pub`) of the type are forwarded directly
-/// to the output type. It also implements:
+/// It takes in one or more structs or enums, with the content being any range expression, which
+/// can be inclusive or not. The attributes and visibility (e.g. `pub`) of the type are forwarded
+/// directly to the output type. It also implements:
 /// * `Debug`, `Display`, `Binary`, `LowerExp`, `LowerHex`, `Octal`, `UpperExp` and `UpperHex`
 /// * `Hash`
 /// * `Clone` and `Copy`
 /// * `PartialEq` and `Eq`
 /// * `PartialOrd` and `Ord`
+/// * `From<Self>` and `From<&Self>` for the underlying primitive
+/// * `bounded_integer::BoundedInteger`
 /// * If the `serde` feature is enabled, `Serialize` and `Deserialize`
 ///
 /// The item must have a `repr` attribute to specify how it will be represented in memory, and it
-/// must be a `u*` or `i*` type.
+/// must be a `u*` or `i*` type, `char` for [bounded chars](#chars) or `f32` or `f64` for
+/// [bounded floats](#floats).
 ///
 /// # Examples
 /// With a struct:
@@ -69,12 +80,14 @@ use syn::{ExprLit, Lit};
 ///
 /// # Custom path to bounded integer
 ///
-/// If your are using the `serde` feature and have `bounded_integer` at a path other than
+/// The generated code refers to items of the `bounded_integer` crate, such as the
+/// `BoundedInteger` trait. If you have `bounded_integer` at a path other than
 /// `::bounded_integer`, then you will need to tell `bounded_integer` the correct path. For example
 /// if `bounded_integer` is instead located at `path::to::bounded_integer`:
 ///
 /// ```rust
 /// # mod force_item_scope {
+/// # mod path { pub mod to { pub use ::bounded_integer; } }
 /// # use bounded_integer_macro::bounded_integer;
 /// # #[cfg(not(feature = "serde"))]
 /// bounded_integer! {
@@ -85,19 +98,553 @@ use syn::{ExprLit, Lit};
 /// # }
 /// ```
 ///
+/// The path can also be given as an option,
+/// `#[bounded_integer(crate = path::to::bounded_integer)]`, which is the only form accepted by the
+/// derive macro.
+///
+/// # Attribute form
+///
+/// The same bounded integers can be generated from regular struct and enum items with the
+/// attribute form of this macro, [`bounded_integer_attr`](macro@bounded_integer_attr).
+///
+/// # Deriving
+///
+/// The API of a bounded integer can also be implemented for an enum whose variants you name
+/// yourself with [`derive(BoundedInteger)`](derive@BoundedInteger).
+///
+/// # Multiple items
+///
+/// Several bounded integers can be generated by one invocation of the macro. Inner attributes
+/// (`#![...]`) at the start of the input are applied to every item as if they were written before
+/// the item's own attributes, so they can set a shared repr, crate path, derives and options.
+/// Attributes on an item take precedence over the shared ones: its own `repr` replaces the shared
+/// one, and its options are applied after the shared options.
+///
+/// ```rust
+/// # mod force_item_scope {
+/// # use bounded_integer_macro::bounded_integer;
+/// # #[cfg(not(feature = "serde"))]
+/// bounded_integer! {
+///     #![repr(u8)]
+///     #![bounded_integer(overflow = saturate)]
+///
+///     pub struct Channel { 1..=16 }
+///     pub struct Velocity { 0..128 }
+///     #[repr(u16)]
+///     pub struct Pitch { 0..16384 }
+/// }
+/// # }
+/// ```
+///
+/// # Options
+///
+/// Further options can be set with one or more `#[bounded_integer(...)]` attributes, each holding
+/// a comma-separated list of options.
+///
+/// ## Derives and trait implementations
+///
+/// Traits to derive in addition to the default ones can be given with `derive(...)` or with a
+/// `#[derive(...)]` attribute on the item; default derives that are also derived by the user are
+/// only derived once. `skip(...)` leaves out default derives and trait implementations so that
+/// they can be implemented manually. It takes:
+/// - `Debug` or `Hash`, to skip the derive.
+/// - The name of an operator trait (`Add`, `Sub`, `Mul`, `Div`, `Rem` or `Neg`), or `ops` to skip
+///   all of them.
+/// - The name of a formatting trait (`Binary`, `Display`, `LowerExp`, `LowerHex`, `Octal`,
+///   `UpperExp` or `UpperHex`), or `fmt` to skip all of them.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// use std::fmt::{self, Display, Formatter};
+///
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[derive(Debug)]
+///     #[bounded_integer(default, skip(Display, Div, Rem))]
+///     pub struct Level { 0..=10 }
+/// }
+///
+/// impl Display for Level {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         write!(f, "level {}", self.get())
+///     }
+/// }
+///
+/// assert_eq!(Level::default(), Level::MIN);
+/// assert_eq!(Level::MAX.to_string(), "level 10");
+/// # }
+/// ```
+///
+/// ## Default
+///
+/// Bounded integers don't implement `Default` unless the `default` option is given:
+/// - `default = value` uses the given value, which is checked to be in range at compile time.
+/// - `default = MIN` and `default = MAX` use the smallest and largest value.
+/// - `default` on its own uses zero. If the macro can evaluate the range and it doesn't contain
+///   zero, `Default` isn't implemented.
+///
+/// Deriving `Default` for a generated struct or enum is an error, as it could create a value that
+/// is out of range. Enums with `derive(BoundedInteger)` can still derive it, as their `#[default]`
+/// variant is always in range.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(default = 50)]
+///     pub struct Volume { 0..=100 }
+/// }
+/// bounded_integer! {
+///     #[repr(i8)]
+///     #[bounded_integer(default = MIN)]
+///     pub enum Floor { -2..10 }
+/// }
+///
+/// #[derive(Default)]
+/// struct Settings {
+///     volume: Volume,
+///     floor: Floor,
+/// }
+///
+/// let settings = Settings::default();
+/// assert_eq!(settings.volume.get(), 50);
+/// assert_eq!(settings.floor, Floor::N2);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(default = 101)]
+///     pub struct Volume { 0..=100 }
+/// }
+/// ```
+///
+/// ## Named constants
+///
+/// The `consts(NAME = value, ...)` option generates associated constants of the bounded integer
+/// with the given values, which are checked to be in range at compile time. The constants can be
+/// documented by putting doc comments before their names.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(consts(
+///         /// No sound at all.
+///         MUTE = 0,
+///         LOUD = 90,
+///     ))]
+///     pub struct Volume { 0..=100 }
+/// }
+/// bounded_integer! {
+///     #[repr(i8)]
+///     #[bounded_integer(consts(GROUND = 0))]
+///     pub enum Floor { -2..10 }
+/// }
+///
+/// assert_eq!(Volume::MUTE, Volume::MIN);
+/// assert_eq!(Volume::LOUD.get(), 90);
+/// assert_eq!(Floor::GROUND, Floor::Z0);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(consts(LOUDER = 101))]
+///     pub struct Volume { 0..=100 }
+/// }
+/// ```
+///
+/// ## Modular arithmetic
+///
+/// With the `modular` option the bounded integer behaves as the integers modulo `RANGE`. The
+/// range must start at 0. Instead of panicking, `+`, `-` and `*` wrap around, `/` and `%` wrap
+/// their result, and `Neg` is implemented even for unsigned types. The methods `inverse`, which
+/// computes the modular multiplicative inverse if there is one, and `pow_mod` are added.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(modular)]
+///     pub struct Hour { 0..12 }
+/// }
+///
+/// assert_eq!((Hour::new(9).unwrap() + 5).get(), 2);
+/// assert_eq!((-Hour::new(5).unwrap()).get(), 7);
+/// assert_eq!(Hour::new(5).unwrap().inverse(), Hour::new(5));
+/// # }
+/// ```
+///
+/// ## Automatic repr
+///
+/// If the repr is left out or given as `#[repr(auto)]`, the smallest primitive integer type that
+/// can hold the range is chosen, preferring unsigned types. Both bounds of the range must then be
+/// simple const expressions, as described in the [limitations](#limitations). The chosen type is
+/// mentioned in the documentation of the generated item, and can be named as
+/// `<T as BoundedInteger>::Repr`.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::{bounded_integer, BoundedInteger};
+/// bounded_integer! {
+///     pub struct Percentage { 0..=100 }
+/// }
+/// bounded_integer! {
+///     #[repr(auto)]
+///     pub enum Offset { -200..200 }
+/// }
+///
+/// let value: <Percentage as BoundedInteger>::Repr = Percentage::MAX.get();
+/// assert_eq!(value, 100_u8);
+/// assert_eq!(Offset::MIN.get(), -200_i16);
+/// # }
+/// ```
+///
+/// ## Type aliases
+///
+/// The repr of a bounded integer must be one of the primitive integer types, optionally written
+/// with a `core::primitive` or `std::primitive` prefix. Structs can also use an alias or
+/// re-export of a primitive integer type if they give its signedness with `signed = true` or
+/// `signed = false`.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// type Word = u16;
+///
+/// bounded_integer! {
+///     #[repr(Word)]
+///     #[bounded_integer(signed = false)]
+///     pub struct Address { 0x100.. }
+/// }
+///
+/// assert_eq!(Address::MAX_VALUE, Word::MAX);
+/// # }
+/// ```
+///
+/// ## Enum variants
+///
+/// By default the variants of enums are named `N{n}` for negative values, `Z0` for zero and
+/// `P{n}` for positive values, where `{n}` is the absolute value. The `variants = "..."` option
+/// sets the format used for zero and positive values, and `negative_variants = "..."` the format
+/// used for negative values, where `{}` is replaced with the absolute value. Individual variants
+/// can be given different names with `rename(value = Name, ...)`. Each value can only be renamed
+/// once, and the names of the variants must all be different.
+///
+/// The derived `Debug` implementation of enums prints the name of the variant. With the
+/// `numeric_debug` option, enums instead print their value the same way structs do.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(i8)]
+///     #[bounded_integer(variants = "Plus{}", negative_variants = "Minus{}")]
+///     #[bounded_integer(rename(0 = Zero), numeric_debug)]
+///     pub enum Offset { -2..=2 }
+/// }
+///
+/// assert_eq!(Offset::MIN, Offset::Minus2);
+/// assert_eq!(Offset::new(0), Some(Offset::Zero));
+/// assert_eq!(format!("{:?}", Offset::Plus1), "Offset(1)");
+/// # }
+/// ```
+///
+/// ## Gaps and steps
+///
+/// Instead of a single range, the values of a bounded integer can be given as ranges and single
+/// values separated by `|`, and ranges can be followed by `step n` to only include every `n`th
+/// value from their start. The macro has to evaluate these values, so they are subject to the
+/// same limitations as the ranges of enums and the repr must be a primitive integer type. Enums
+/// only get variants for the values in the set.
+///
+/// `MIN` and `MAX` are the smallest and largest values, `RANGE` is the number of values and
+/// checked operations fail for values in a gap. `new_saturating` moves values in a gap to the
+/// nearest valid value, preferring the smaller one on ties, and `new_wrapping` wraps values into
+/// the range from `MIN` to `MAX` before doing the same. `values()`, which iterates over the values
+/// of any bounded integer in increasing order, skips the gaps. Modular bounded integers can't have
+/// gaps.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     pub struct Even { 0..=100 step 2 }
+/// }
+/// bounded_integer! {
+///     #[repr(u8)]
+///     pub enum Dice { 4 | 6 | 8 | 10 | 12 | 20 }
+/// }
+///
+/// assert_eq!(Even::new(43), None);
+/// assert_eq!(Even::new_saturating(43).get(), 42);
+/// assert_eq!(Dice::RANGE, 6);
+/// assert_eq!(Dice::new_saturating(15), Dice::P12);
+/// assert_eq!(Dice::values().nth(1), Some(Dice::P6));
+/// # }
+/// ```
+///
+/// ## Checks
+///
+/// Invariants that aren't ranges can be given with `check = path`, naming a
+/// `const fn(repr) -> bool` that values have to pass in addition to being in range. It is used by
+/// `in_range`, so every constructor, operator, checked method and `Deserialize` implementation
+/// enforces it. The minimum and maximum must pass the check, which is asserted at compile time.
+///
+/// A value that is rejected by the check can't be moved to one that passes it without searching,
+/// so bounded integers with a check don't have `new_saturating`, `new_wrapping` or the
+/// `saturating_` methods, and don't implement the `BoundedInteger` trait, which requires them.
+/// Checks can only be used with structs, and not with modular bounded integers or with
+/// `overflow = saturate`; their operators panic on values that fail the check.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// const fn is_power_of_two(n: u16) -> bool {
+///     n.is_power_of_two()
+/// }
+///
+/// bounded_integer! {
+///     #[repr(u16)]
+///     #[bounded_integer(check = is_power_of_two)]
+///     pub struct BlockSize { 512..=4096 }
+/// }
+///
+/// assert_eq!(BlockSize::new(1000), None);
+/// assert_eq!(BlockSize::MIN.checked_mul(2).unwrap().get(), 1024);
+/// assert_eq!(BlockSize::MAX.checked_mul(2), None);
+/// # }
+/// ```
+///
+/// ## Chars
+///
+/// Structs with a `char` repr are bounded chars, whose values are a range of chars. The repr is
+/// chosen automatically if both bounds are character literals, and the end of a half-open range
+/// has to be a character literal. Bounded chars have the same constants and constructors as
+/// bounded integers, where `RANGE` and `new_wrapping` skip the surrogates, which aren't chars.
+/// They also have:
+/// - `succ` and `pred`, which get the next and previous value.
+/// - `values`, an iterator over every value.
+/// - `TryFrom<char>`, and `FromStr` for strings holding exactly one char.
+///
+/// Arithmetic operators and methods, the formatting traits other than `Display`, the modular
+/// option and `default` on its own aren't available for chars.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     pub struct Letter { 'a'..='z' }
+/// }
+///
+/// let letter: Letter = "q".parse().unwrap();
+/// assert_eq!(letter.succ().map(Letter::get), Some('r'));
+/// assert_eq!(Letter::MAX.succ(), None);
+/// assert_eq!(Letter::values().count(), 26);
+/// assert_eq!(char::from(Letter::MIN), 'a');
+/// # }
+/// ```
+///
+/// ## Floats
+///
+/// Structs with an `f32` or `f64` repr are bounded floats, whose values are a range of floats
+/// that excludes NaN. The repr is `f64` if it is chosen automatically from float literals. The
+/// range must be inclusive, and a missing bound means infinity. As NaN is excluded, bounded
+/// floats implement `Eq`, `Ord` and `Hash`, treating `-0.0` and `0.0` as equal.
+///
+/// `new` rejects NaN, `new_saturating` clamps values to the range and panics on NaN, and
+/// `new_wrapping` wraps values by their distance from `MIN` and panics on NaN. Infinities, and
+/// values too far away for their distance to be finite, can't be wrapped, so `new_wrapping`
+/// saturates them instead. The arithmetic operators and the `checked_` and `saturating_` methods
+/// for addition, subtraction, multiplication, division, remainder, negation and absolute value are
+/// available, following the `overflow` option. Saturating arithmetic never panics: a result that
+/// is NaN, such as the product of zero and infinity, gives `MIN`. `RANGE` is the number of
+/// distinct floats in the range.
+///
+/// The formatting traits other than `Display`, `LowerExp` and `UpperExp`, the modular option and
+/// the `BoundedInteger` trait, which requires an `Ord` repr, aren't available for floats.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(f64)]
+///     pub struct Probability { 0.0..=1.0 }
+/// }
+///
+/// let half = Probability::new(0.5).unwrap();
+/// assert_eq!(Probability::new(f64::NAN), None);
+/// assert_eq!(half.checked_add(0.75), None);
+/// assert_eq!(half.saturating_mul(3.0), Probability::MAX);
+/// assert_eq!([half, Probability::MAX, Probability::MIN].iter().max(), Some(&Probability::MAX));
+/// # }
+/// ```
+///
+/// ## Fixed-point
+///
+/// The `scale = n` option, where `n` is a power of 10 that fits in the repr, makes a struct a
+/// fixed-point number: it still stores an integer, but the integer is read as a number of `1/n`
+/// units. A `SCALE` constant is generated, `Display` prints the value as a decimal with one digit
+/// after the point per zero in the scale, and `FromStr` parses such decimals, rejecting ones with
+/// more digits than the scale allows with a `ParseDecimalError`. `to_f64` and
+/// `from_f64(x, rounding)` convert to and from `f64`, rounding by the given `Rounding` mode.
+///
+/// Multiplying or dividing two values of the type with `Mul` and `Div` rescales the result,
+/// following the `overflow` option, and the same is available as `checked_mul_scaled`,
+/// `saturating_mul_scaled`, `checked_div_scaled` and `saturating_div_scaled`. The intermediate
+/// product is computed exactly, even for 128-bit reprs, so these only overflow if the rescaled
+/// result does. Operators with the repr on the right-hand side aren't rescaled, and the
+/// `_bounded` versions of multiplication and division aren't generated. The scale can't be used
+/// with modular bounded integers, chars or floats.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::{bounded_integer, Rounding};
+/// bounded_integer! {
+///     #[repr(u16)]
+///     #[bounded_integer(scale = 100)]
+///     pub struct Percent { 0..=10_000 }
+/// }
+///
+/// let value: Percent = "12.5".parse().unwrap();
+/// assert_eq!(value.get(), 1250);
+/// assert_eq!(value.to_string(), "12.50");
+/// assert!("12.345".parse::<Percent>().is_err());
+/// assert_eq!(Percent::from_f64(0.125, Rounding::Down).unwrap().to_string(), "0.12");
+/// let two: Percent = "2".parse().unwrap();
+/// assert_eq!((value * two).to_string(), "25.00");
+/// assert_eq!(value.checked_div_scaled(Percent::MIN), None);
+/// # }
+/// ```
+///
+/// ## Large enums
+///
+/// Enums have one variant per value, so large ranges make them slow to compile. Enums with more
+/// than 65536 variants are rejected; the limit can be changed with `max_variants = n`. With the
+/// `auto_form` option, enums above the limit are generated as structs instead, which have the
+/// same API apart from the variants. The variant naming options are rejected when this happens,
+/// as there are no variants to name.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u32)]
+///     #[bounded_integer(auto_form)]
+///     pub enum Large { 0..=4_000_000 }
+/// }
+///
+/// assert_eq!(Large::MAX.get(), 4_000_000);
+/// # }
+/// ```
+///
+/// ## Overflow behaviour
+///
+/// By default the arithmetic operator traits (`Add`, `Sub`, `Mul`, `Div`, `Rem` and `Neg`, along
+/// with their assigning versions) panic if the result is out of range. This can be made explicit
+/// with `overflow = panic`, or they can be made to saturate at `MIN` and `MAX` instead with
+/// `overflow = saturate`. Division by zero still panics.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// # use bounded_integer::bounded_integer;
+/// bounded_integer! {
+///     #[repr(u8)]
+///     #[bounded_integer(overflow = saturate)]
+///     pub struct Volume { 0..=10 }
+/// }
+///
+/// assert_eq!(Volume::new(8).unwrap() + 5, Volume::MAX);
+/// assert_eq!(Volume::new(8).unwrap() - 20, Volume::MIN);
+/// # }
+/// ```
+///
 /// # Limitations
 ///
 /// - Both bounds of enum ranges must be closed and be a simple const expression involving only
-/// literals and the following operators:
+/// literals, primitive constants and the following operators:
 ///     - Negation (`-x`)
 ///     - Addition (`x+y`), subtraction (`x-y`), multiplication (`x*y`), division (`x/y`) and
 ///     remainder (`x%y`).
 ///     - Bitwise not (`!x`), XOR (`x^y`), AND (`x&y`) and OR (`x|y`).
-/// - The above limitations do not apply to struct ranges.
+///     - Left shift (`x<<y`) and right shift (`x>>y`).
+///     - The `MIN`, `MAX` and `BITS` constants of the primitive integer types (`u8::MAX`,
+///       `core::primitive::i16::MIN`).
+///
+///   These expressions are evaluated in the repr of the enum, so every intermediate value must
+///   fit in it. `usize` and `isize` are assumed to be 64 bits wide. A `|` that isn't in
+///   parentheses separates values, so bitwise OR has to be parenthesized.
+/// - The above limitations do not apply to struct ranges. Struct bounds written this way are
+///   still checked by the macro, and other bounds are checked at compile time.
 
 #[proc_macro]
 pub fn bounded_integer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let bounded_integer = parse_macro_input!(input as BoundedInteger);
+    let BoundedIntegers(bounded_integers) = parse_macro_input!(input as BoundedIntegers);
+
+    let mut result = TokenStream::new();
+    for bounded_integer in &bounded_integers {
+        bounded_integer.generate_item(&mut result);
+        bounded_integer.generate_impl(&mut result);
+    }
+    result.into()
+}
+
+/// Generate a bounded integer type from a struct or enum item.
+///
+/// This is the attribute form of the [`bounded_integer!`](macro@bounded_integer) macro, re-exported
+/// as `bounded_integer::attr::bounded_integer`. Unlike the function-like macro its input is valid
+/// Rust syntax, so it works well with tools like rustfmt and rust-analyzer. It takes the range as
+/// its argument, optionally followed by the options that would otherwise be given in a
+/// `#[bounded_integer(...)]` attribute, and generates exactly the same code as the function-like
+/// macro.
+///
+/// The item can be a unit struct with a `repr` attribute, a tuple struct whose single private
+/// field is the representation, or an empty enum with a `repr` attribute. Other
+/// `#[bounded_integer]` attributes on the item must come after this one.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// use bounded_integer::attr::bounded_integer;
+///
+/// #[bounded_integer(-8..8)]
+/// #[repr(i8)]
+/// pub struct Unit;
+///
+/// #[bounded_integer(-8..8, overflow = saturate)]
+/// pub struct Tuple(i8);
+///
+/// #[bounded_integer(0..12)]
+/// #[bounded_integer(modular)]
+/// #[repr(u8)]
+/// pub enum Enum {}
+///
+/// assert_eq!(Unit::MIN.get(), -8);
+/// assert_eq!(Tuple::new(7).unwrap() + 1, Tuple::MAX);
+/// assert_eq!(Enum::P11 + 1, Enum::Z0);
+/// # }
+/// ```
+#[proc_macro_attribute]
+pub fn bounded_integer_attr(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as Item);
+    let bounded_integer = match BoundedInteger::from_attribute(args.into(), item) {
+        Ok(bounded_integer) => bounded_integer,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     let mut result = TokenStream::new();
     bounded_integer.generate_item(&mut result);
@@ -105,11 +652,61 @@ pub fn bounded_integer(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
     result.into()
 }
 
+/// Implement the API of a bounded integer for an enum with user-declared variants.
+///
+/// The enum must have a `repr` attribute and unit variants whose discriminants form a single
+/// contiguous run of values, in any order. The bounds of the bounded integer are the smallest and
+/// largest discriminants. This generates the same associated items, methods and trait
+/// implementations as [`bounded_integer!`](macro@bounded_integer), but the enum itself is left
+/// as-is, so the traits it derives have to be given explicitly; `Clone`, `Copy`, `PartialEq`, `Eq`,
+/// `PartialOrd` and `Ord` are required.
+///
+/// The crate path and options are given with `#[bounded_integer(...)]` attributes like in the
+/// function-like macro.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// use bounded_integer::BoundedInteger;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+/// #[repr(u8)]
+/// #[bounded_integer(modular)]
+/// enum Weekday {
+///     Sunday,
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+/// }
+///
+/// assert_eq!(Weekday::MIN, Weekday::Sunday);
+/// assert_eq!(Weekday::new(2), Some(Weekday::Tuesday));
+/// assert_eq!(Weekday::Friday + 3, Weekday::Monday);
+/// # }
+/// ```
+#[proc_macro_derive(BoundedInteger, attributes(bounded_integer))]
+pub fn derive_bounded_integer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let bounded_integer = match BoundedInteger::from_derive(input) {
+        Ok(bounded_integer) => bounded_integer,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let mut result = TokenStream::new();
+    bounded_integer.generate_impl(&mut result);
+    result.into()
+}
+
 #[allow(dead_code)]
 enum BoundedInteger {
     Struct {
         attrs: Vec<Attribute>,
         crate_location: Path,
+        options: Options,
         repr: Path,
         repr_unsigned: bool,
         vis: Visibility,
@@ -117,17 +714,23 @@ enum BoundedInteger {
         ident: Ident,
         brace_token: Brace,
         range: Box<(Option<Expr>, Option<Expr>)>,
+        /// The values of the bounded integer if they don't form a single range, in which case
+        /// `range` holds the smallest and largest value.
+        values: Option<ValueSet>,
     },
     Enum {
         attrs: Vec<Attribute>,
         crate_location: Path,
+        options: Options,
         repr: Path,
         repr_unsigned: bool,
         vis: Visibility,
         enum_token: Token![enum],
         ident: Ident,
         brace_token: Brace,
-        range: RangeInclusive<isize>,
+        values: ValueSet,
+        /// The names of the variants, in order of their values.
+        variants: Vec<Ident>,
         semi_token: Option<Token![;]>,
     },
 }
@@ -137,9 +740,77 @@ impl BoundedInteger {
         for attr in self.attrs() {
             attr.to_tokens(tokens);
         }
-        tokens.extend(quote! {
-            #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-        });
+        if self.options().modular {
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = "This is a modular integer: the arithmetic operators wrap around modulo `RANGE`"]
+                #[doc = "instead of panicking, so `+`, `-` and `*` form the ring of integers modulo"]
+                #[doc = "`RANGE`. Addition and multiplication are associative and commutative,"]
+                #[doc = "multiplication distributes over addition, `MIN` is the additive identity and"]
+                #[doc = "every value has an additive inverse given by `-`."]
+            });
+        }
+        if self.options().auto_repr && self.options().non_integer_kind().is_none() {
+            let repr = self.repr();
+            let doc = format!(
+                "This bounded integer is represented as a `{}`, the smallest type that can hold \
+                its range. It can be named as `<{} as BoundedInteger>::Repr`.",
+                quote!(#repr),
+                self.ident(),
+            );
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = #doc]
+            });
+        }
+        if self.options().overflow == Overflow::Saturate {
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = "The arithmetic operators of this type saturate: instead of panicking, results"]
+                #[doc = "below `MIN` or above `MAX` are clamped to `MIN` or `MAX` respectively."]
+            });
+        }
+        if let Some(check) = &self.options().check {
+            let doc = format!(
+                "Values of this type must also pass `{}`, and its operators panic if their result \
+                doesn't. It has no `new_saturating`, `new_wrapping` or saturating methods, as they \
+                couldn't always find a value that passes.",
+                quote!(#check),
+            );
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = #doc]
+            });
+        }
+        if let Some((scale, digits)) = &self.options().scale {
+            let doc = format!(
+                "This is a fixed-point bounded integer with a scale of {}: it is formatted and \
+                parsed as a decimal with {} digits after the point, and multiplying or dividing it \
+                by itself rescales the result.",
+                scale, digits,
+            );
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = #doc]
+            });
+        }
+        if self.options().float_repr {
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = "This bounded float is never NaN, so it implements `Eq`, `Ord` and `Hash`,"]
+                #[doc = "which treat `-0.0` and `0.0` as equal. Saturating and wrapping operations"]
+                #[doc = "panic if their result is NaN."]
+            });
+        }
+        if self.gaps().is_some() {
+            tokens.extend(quote! {
+                #[doc = ""]
+                #[doc = "The values of this type have gaps between them. Saturating and wrapping"]
+                #[doc = "operations move results that fall into a gap to the nearest valid value."]
+            });
+        }
+        let derives = self.derives();
+        tokens.extend(quote!(#[derive(#(#derives),*)]));
 
         match self {
             Self::Struct {
@@ -162,7 +833,7 @@ impl BoundedInteger {
                 enum_token,
                 ident,
                 brace_token,
-                range,
+                values,
                 semi_token,
                 ..
             } => {
@@ -173,16 +844,18 @@ impl BoundedInteger {
 
                 let mut inner_tokens = TokenStream::new();
 
-                let mut variants = range.clone().map(enum_variant);
-
-                if let Some(first_variant) = variants.next() {
-                    first_variant.to_tokens(&mut inner_tokens);
-                    Token![=](Span::call_site()).to_tokens(&mut inner_tokens);
-                    inner_tokens.append(Literal::isize_unsuffixed(*range.start()));
-                }
-                for variant in variants {
-                    Token![,](Span::call_site()).to_tokens(&mut inner_tokens);
+                // Only the first discriminant is needed if there are no gaps between the values.
+                let explicit = if values.is_range() { 1 } else { usize::MAX };
+                let variants = self.variants().iter().zip(values.values());
+                for (i, (variant, value)) in variants.enumerate() {
+                    if i > 0 {
+                        Token![,](Span::call_site()).to_tokens(&mut inner_tokens);
+                    }
                     variant.to_tokens(&mut inner_tokens);
+                    if i < explicit {
+                        Token![=](Span::call_site()).to_tokens(&mut inner_tokens);
+                        inner_tokens.append(value.literal());
+                    }
                 }
 
                 tokens.extend(quote_spanned!(brace_token.span=> { #inner_tokens }));
@@ -200,25 +873,54 @@ impl BoundedInteger {
             Self::Struct { range, .. } => {
                 min_value = match &range.0 {
                     Some(from) => from.into_token_stream(),
-                    None => quote!(::core::primitive::#repr::MIN),
+                    None if self.options().char_repr => quote!('\0'),
+                    None if self.options().float_repr => quote!(<#repr>::NEG_INFINITY),
+                    None => quote!(<#repr>::MIN),
                 };
                 min = quote!(Self(Self::MIN_VALUE));
                 max_value = match &range.1 {
                     Some(to) => to.into_token_stream(),
-                    None => quote!(::core::primitive::#repr::MAX),
+                    None if self.options().float_repr => quote!(<#repr>::INFINITY),
+                    None => quote!(<#repr>::MAX),
                 };
                 max = quote!(Self(Self::MAX_VALUE));
             }
-            Self::Enum { range, .. } => {
-                min_value = Literal::isize_unsuffixed(*range.start()).into_token_stream();
-                max_value = Literal::isize_unsuffixed(*range.end()).into_token_stream();
-                let min_variant = enum_variant(*range.start());
-                let max_variant = enum_variant(*range.end());
+            Self::Enum { values, .. } => {
+                min_value = values.min().literal().into_token_stream();
+                max_value = values.max().literal().into_token_stream();
+                let min_variant = self.variant(values.min());
+                let max_variant = self.variant(values.max());
                 min = quote!(Self::#min_variant);
                 max = quote!(Self::#max_variant);
             }
         }
 
+        let crate_location = self.crate_location();
+        let range = match self.gaps() {
+            Some(values) => {
+                let range = Literal::u128_unsuffixed(values.last_index() + 1);
+                quote!(#range)
+            }
+            None if self.options().char_repr => quote! {
+                #crate_location::__private::char_count(Self::MIN_VALUE, Self::MAX_VALUE)
+            },
+            None if self.options().float_repr => {
+                let ty = &repr.segments.last().unwrap().ident;
+                let index = Ident::new(&format!("{}_index", ty), Span::call_site());
+                quote! {
+                    #crate_location::__private::float_count(
+                        #crate_location::__private::#index(Self::MIN_VALUE),
+                        #crate_location::__private::#index(Self::MAX_VALUE),
+                    )
+                }
+            }
+            None => quote! {
+                (Self::MAX_VALUE as ::core::primitive::u128)
+                    .wrapping_sub(Self::MIN_VALUE as ::core::primitive::u128)
+                    .wrapping_add(1)
+            },
+        };
+
         tokens.extend(quote! {
             /// The smallest value that this bounded integer can contain.
             #vis const MIN_VALUE: #repr = #min_value;
@@ -230,14 +932,32 @@ impl BoundedInteger {
             /// The largest value of the bounded integer.
             #vis const MAX: Self = #max;
 
-            /// The number of values the bounded integer can contain.
-            #vis const RANGE: #repr = Self::MAX_VALUE - Self::MIN_VALUE + 1;
+            /// The number of values the bounded integer can contain. This is 0 if it can contain
+            /// all 2<sup>128</sup> values of a 128-bit integer.
+            #vis const RANGE: ::core::primitive::u128 = #range;
         });
+
+        if let Some((scale, _)) = &self.options().scale {
+            tokens.extend(quote! {
+                /// The value that represents 1, as this is a fixed-point bounded integer.
+                #vis const SCALE: #repr = #scale;
+            });
+        }
+
+        let ident = self.ident();
+        for NamedConst { attrs, name, value } in &self.options().consts {
+            let value = quote_spanned!(value.span()=> #crate_location::bounded!(#ident, #value));
+            tokens.extend(quote! {
+                #(#attrs)*
+                #vis const #name: Self = #value;
+            });
+        }
     }
 
     fn generate_base(&self, tokens: &mut TokenStream) {
         let vis = self.vis();
         let repr = self.repr();
+        let crate_location = self.crate_location();
 
         let (get_body, new_body, low_bounded, high_bounded) = match self {
             Self::Struct { range, .. } => (
@@ -254,8 +974,11 @@ impl BoundedInteger {
             ),
         };
 
+        // Comparing with a bound rejects NaN, so floats without bounds have to reject it instead.
         let low_check = if low_bounded {
             quote!(n >= Self::MIN_VALUE)
+        } else if self.options().float_repr {
+            quote!(!n.is_nan())
         } else {
             quote!(true)
         };
@@ -265,6 +988,99 @@ impl BoundedInteger {
             quote!(true)
         };
 
+        // Values between `MIN` and `MAX` are either always valid or are looked up in the set of
+        // values, as offsets from `MIN`.
+        let offset = quote! {
+            (n as ::core::primitive::u128).wrapping_sub(Self::MIN_VALUE as ::core::primitive::u128)
+        };
+        let check = &self.options().check;
+        let construct = |value: TokenStream| {
+            quote! {
+                // SAFETY: The value is in range.
+                unsafe { Self::new_unchecked(#value) }
+            }
+        };
+        let (set_check, in_range_value, wrapped_value) = match self.gaps() {
+            Some(values) => {
+                let parts = set_parts(values);
+                let value = construct(quote!(n as #repr));
+                (
+                    quote!(&& #crate_location::__private::set_contains(#offset, #parts)),
+                    quote! {
+                        let nearest = #crate_location::__private::set_nearest(#offset, #parts);
+                        let n = (Self::MIN_VALUE as ::core::primitive::u128).wrapping_add(nearest);
+                        #value
+                    },
+                    // Values are wrapped from `MIN` to `MAX` and then moved out of gaps.
+                    quote!(Self::new_saturating(wrapped as #repr)),
+                )
+            }
+            None => (
+                TokenStream::new(),
+                construct(quote!(n)),
+                construct(quote!(wrapped as #repr)),
+            ),
+        };
+        let predicate_check = check.as_ref().map(|check| quote!(&& #check(n)));
+
+        let nan_check = if self.options().float_repr {
+            quote! {
+                if n.is_nan() {
+                    ::core::panic!("NaN cannot be saturated into the range of a bounded float");
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        // Chars are wrapped by their index among all chars, which skips the surrogates.
+        let wrapping_body = if self.options().char_repr {
+            let value = construct(quote!(wrapped));
+            let private = quote!(#crate_location::__private);
+            quote! {
+                let index = #private::wrap(
+                    #private::char_index(n) as ::core::primitive::u128,
+                    #private::char_index(Self::MIN_VALUE) as ::core::primitive::u128,
+                    Self::RANGE,
+                    n < Self::MIN_VALUE,
+                );
+                let wrapped = #private::char_from_index(index as ::core::primitive::u32);
+                #value
+            }
+        } else if self.options().float_repr {
+            // Floats are wrapped by their distance from `MIN`, which is rounded, so the result
+            // is saturated in case it rounds to just outside the range. Infinities and distances
+            // too large to be finite can't be wrapped, so they are saturated too.
+            quote! {
+                if let ::core::option::Option::Some(value) = Self::new(n) {
+                    return value;
+                }
+                let width = Self::MAX_VALUE - Self::MIN_VALUE;
+                let distance = n - Self::MIN_VALUE;
+                if !width.is_finite() || !distance.is_finite() {
+                    return Self::new_saturating(n);
+                }
+                if width == 0.0 {
+                    return Self::MIN;
+                }
+                let offset = distance % width;
+                let offset = if offset < 0.0 { offset + width } else { offset };
+                Self::new_saturating(Self::MIN_VALUE + offset)
+            }
+        } else {
+            quote! {
+                let wrapped = #crate_location::__private::wrap(
+                    n as ::core::primitive::u128,
+                    Self::MIN_VALUE as ::core::primitive::u128,
+                    (Self::MAX_VALUE as ::core::primitive::u128)
+                        .wrapping_sub(Self::MIN_VALUE as ::core::primitive::u128)
+                        .wrapping_add(1),
+                    n < Self::MIN_VALUE,
+                );
+                #wrapped_value
+            }
+        };
+
         tokens.extend(quote! {
             /// Creates a bounded integer without checking the value.
             ///
@@ -273,19 +1089,19 @@ impl BoundedInteger {
             /// The value must not be outside the valid range of values; it must not be less than
             /// `MIN` or greater than `MAX`.
             #[must_use]
-            #vis unsafe fn new_unchecked(n: #repr) -> Self {
+            #vis const unsafe fn new_unchecked(n: #repr) -> Self {
                 #new_body
             }
 
             /// Checks whether the given value is in the range of the bounded integer.
             #[must_use]
-            #vis fn in_range(n: #repr) -> ::core::primitive::bool {
-                #low_check && #high_check
+            #vis const fn in_range(n: #repr) -> ::core::primitive::bool {
+                #low_check && #high_check #set_check #predicate_check
             }
 
             /// Creates a bounded integer if the given value is within the range [`MIN`, `MAX`].
             #[must_use]
-            #vis fn new(n: #repr) -> ::core::option::Option<Self> {
+            #vis const fn new(n: #repr) -> ::core::option::Option<Self> {
                 if Self::in_range(n) {
                     // SAFETY: We just asserted that the value is in range.
                     Some(unsafe { Self::new_unchecked(n) })
@@ -294,17 +1110,45 @@ impl BoundedInteger {
                 }
             }
 
+            /// Creates a bounded integer, panicking if the given value is out of range.
+            ///
+            /// When used to initialize a constant, an out of range value fails to compile instead.
+            /// The `bounded!` macro does this for any value.
+            #[must_use]
+            #[track_caller]
+            #vis const fn new_const(n: #repr) -> Self {
+                match Self::new(n) {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => {
+                        ::core::panic!("value is out of range of the bounded integer")
+                    }
+                }
+            }
+
+            /// Gets the value of the bounded integer as a primitive type.
+            #[must_use]
+            #vis const fn get(self) -> #repr {
+                #get_body
+            }
+        });
+
+        // Values rejected by a check can't be moved to a valid value cheaply, so bounded integers
+        // with a check can't saturate or wrap.
+        if check.is_some() {
+            return;
+        }
+        tokens.extend(quote! {
             /// Creates a bounded integer by setting the value to `MIN` or `MAX` if it is too low
             /// or too high respectively.
             #[must_use]
             #vis fn new_saturating(n: #repr) -> Self {
+                #nan_check
                 if !(#low_check) {
                     Self::MIN
                 } else if !(#high_check) {
                     Self::MAX
                 } else {
-                    // SAFETY: This branch can only happen if n is in range.
-                    unsafe { Self::new_unchecked(n) }
+                    #in_range_value
                 }
             }
 
@@ -312,18 +1156,7 @@ impl BoundedInteger {
             /// changed but values outside will be wrapped around.
             #[must_use]
             #vis fn new_wrapping(n: #repr) -> Self {
-                unsafe {
-                    Self::new_unchecked(
-                        (n + (Self::RANGE - (Self::MIN_VALUE.rem_euclid(Self::RANGE)))).rem_euclid(Self::RANGE)
-                            + Self::MIN_VALUE
-                    )
-                }
-            }
-
-            /// Gets the value of the bounded integer as a primitive type.
-            #[must_use]
-            #vis fn get(self) -> #repr {
-                #get_body
+                #wrapping_body
             }
         });
     }
@@ -365,19 +1198,54 @@ impl BoundedInteger {
                     .expect("Attempted to divide with remainder out of range")
             }
         });
+
+        if self.options().modular {
+            let crate_location = self.crate_location();
+
+            tokens.extend(quote! {
+                /// Computes the modular multiplicative inverse of `self`, the value that gives
+                /// `MIN + 1` when multiplied by `self`. Returns `None` if `self` is not coprime
+                /// with `RANGE`, in which case no such value exists.
+                #[must_use]
+                #vis fn inverse(self) -> ::core::option::Option<Self> {
+                    #crate_location::__private::mod_inverse(
+                        self.get() as ::core::primitive::u128,
+                        Self::MAX_VALUE as ::core::primitive::u128,
+                    )
+                    // SAFETY: The inverse is always less than `RANGE`.
+                    .map(|inverse| unsafe { Self::new_unchecked(inverse as #repr) })
+                }
+                /// Raises self to the power of `exp` modulo `RANGE`, using exponentiation by
+                /// squaring.
+                #[must_use]
+                #vis fn pow_mod(self, exp: ::core::primitive::u32) -> Self {
+                    let power = #crate_location::__private::mod_pow(
+                        self.get() as ::core::primitive::u128,
+                        exp,
+                        Self::MAX_VALUE as ::core::primitive::u128,
+                    );
+                    // SAFETY: The power is always less than `RANGE`.
+                    unsafe { Self::new_unchecked(power as #repr) }
+                }
+            });
+        }
     }
 
     fn generate_ops_traits(&self, tokens: &mut TokenStream) {
         let ident = self.ident();
         let repr = self.repr();
         let repr_unsigned = self.repr_unsigned();
+        let overflow = self.options().overflow;
+        // Floats have no saturating methods, so their results are saturated afterwards.
+        let float = self.options().float_repr;
 
         for op in OPERATORS {
-            if repr_unsigned && !op.on_unsigned {
+            if repr_unsigned && !op.on_unsigned || self.options().skips(op.trait_name) {
                 continue;
             }
             
             let description = op.description;
+            let saturating = Ident::new(op.saturating, Span::call_site());
 
             if op.bin {
                 binop_trait_variations(
@@ -385,23 +1253,49 @@ impl BoundedInteger {
                     op.method,
                     ident,
                     repr,
-                    |trait_name, method| {
-                        quote! {
+                    |trait_name, method| match overflow {
+                        Overflow::Panic => quote! {
                             Self::new(<#repr as ::core::ops::#trait_name>::#method(self.get(), rhs))
                                 .expect(concat!("Attempted to ", #description, " out of range"))
-                        }
+                        },
+                        Overflow::Saturate if float => float_saturate(quote! {
+                            <#repr as ::core::ops::#trait_name>::#method(self.get(), rhs)
+                        }),
+                        Overflow::Saturate => quote! {
+                            Self::new_saturating(self.get().#saturating(rhs))
+                        },
                     },
                     tokens,
                 );
 
+                // Fixed-point bounded integers rescale when multiplied or divided by themselves.
+                let scaled =
+                    self.options().scale.is_some() && (op.method == "mul" || op.method == "div");
                 binop_trait_variations(
                     op.trait_name,
                     op.method,
                     ident,
                     ident,
-                    |trait_name, method| {
-                        quote! {
+                    |trait_name, method| match overflow {
+                        _ if !scaled => quote! {
                             <Self as ::core::ops::#trait_name<#repr>>::#method(self, rhs.get())
+                        },
+                        Overflow::Panic => {
+                            let checked = Ident::new(
+                                &format!("checked_{}_scaled", method),
+                                Span::call_site(),
+                            );
+                            quote! {
+                                self.#checked(rhs)
+                                    .expect(concat!("Attempted to ", #description, " out of range"))
+                            }
+                        }
+                        Overflow::Saturate => {
+                            let saturating = Ident::new(
+                                &format!("saturating_{}_scaled", method),
+                                Span::call_site(),
+                            );
+                            quote!(self.#saturating(rhs))
                         }
                     },
                     tokens,
@@ -410,13 +1304,113 @@ impl BoundedInteger {
                 let trait_name = Ident::new(op.trait_name, Span::call_site());
                 let method = Ident::new(op.method, Span::call_site());
 
+                let body = match overflow {
+                    Overflow::Panic => quote! {
+                        Self::new(<#repr as ::core::ops::#trait_name>::#method(self.get()))
+                            .expect(concat!("Attempted to ", #description, " out of range"))
+                    },
+                    Overflow::Saturate if float => float_saturate(quote! {
+                        <#repr as ::core::ops::#trait_name>::#method(self.get())
+                    }),
+                    Overflow::Saturate => quote! {
+                        Self::new_saturating(self.get().#saturating())
+                    },
+                };
+
+                unop_trait_variations(&trait_name, &method, ident, &body, tokens);
+            }
+        }
+    }
+
+    fn generate_modular_ops_traits(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+        let crate_location = self.crate_location();
+
+        for op in OPERATORS {
+            if self.options().skips(op.trait_name) {
+                continue;
+            }
+            if !op.bin {
+                let trait_name = Ident::new(op.trait_name, Span::call_site());
+                let method = Ident::new(op.method, Span::call_site());
+
                 unop_trait_variations(
                     &trait_name,
                     &method,
                     ident,
                     &quote! {
-                        Self::new(<#repr as ::core::ops::#trait_name>::#method(self.get()))
-                            .expect(concat!("Attempted to ", #description, " out of range"))
+                        let negated = #crate_location::__private::mod_neg(
+                            self.get() as ::core::primitive::u128,
+                            Self::MAX_VALUE as ::core::primitive::u128,
+                        );
+                        // SAFETY: The negation is always less than `RANGE`.
+                        unsafe { Self::new_unchecked(negated as #repr) }
+                    },
+                    tokens,
+                );
+                continue;
+            }
+
+            if let "add" | "sub" | "mul" = op.method {
+                // Ring operations are performed on two reduced values, and a primitive
+                // right-hand side is reduced before being applied.
+                let mod_method = Ident::new(&format!("mod_{}", op.method), Span::call_site());
+
+                binop_trait_variations(
+                    op.trait_name,
+                    op.method,
+                    ident,
+                    ident,
+                    |_, _| {
+                        quote! {
+                            let result = #crate_location::__private::#mod_method(
+                                self.get() as ::core::primitive::u128,
+                                rhs.get() as ::core::primitive::u128,
+                                Self::MAX_VALUE as ::core::primitive::u128,
+                            );
+                            // SAFETY: The result is always less than `RANGE`.
+                            unsafe { Self::new_unchecked(result as #repr) }
+                        }
+                    },
+                    tokens,
+                );
+
+                binop_trait_variations(
+                    op.trait_name,
+                    op.method,
+                    ident,
+                    repr,
+                    |trait_name, method| {
+                        quote! {
+                            <Self as ::core::ops::#trait_name>::#method(self, Self::new_wrapping(rhs))
+                        }
+                    },
+                    tokens,
+                );
+            } else {
+                binop_trait_variations(
+                    op.trait_name,
+                    op.method,
+                    ident,
+                    repr,
+                    |trait_name, method| {
+                        quote! {
+                            Self::new_wrapping(<#repr as ::core::ops::#trait_name>::#method(self.get(), rhs))
+                        }
+                    },
+                    tokens,
+                );
+
+                binop_trait_variations(
+                    op.trait_name,
+                    op.method,
+                    ident,
+                    ident,
+                    |trait_name, method| {
+                        quote! {
+                            <Self as ::core::ops::#trait_name<#repr>>::#method(self, rhs.get())
+                        }
                     },
                     tokens,
                 );
@@ -426,12 +1420,26 @@ impl BoundedInteger {
 
     fn generate_checked_operators(&self, tokens: &mut TokenStream) {
         let vis = self.vis();
+        let repr = self.repr();
         let repr_unsigned = self.repr_unsigned();
 
         for op in CHECKED_OPERATORS {
             if repr_unsigned && op.on_unsigned == CheckedOnUnsigned::None {
                 continue;
             }
+            // Floats use their operators directly, and only have some of the operations.
+            let float_value = if self.options().float_repr {
+                match float_operation(op.name) {
+                    Some(value) => Some(value),
+                    None => continue,
+                }
+            } else {
+                None
+            };
+            let description = match float_value {
+                Some(_) => op.description.trim_start_matches("integer "),
+                None => op.description,
+            };
 
             // Dummy storage to extend the lifetime of rhs.
             let mut rhs_ident_storage = None;
@@ -446,112 +1454,581 @@ impl BoundedInteger {
             let rhs_value = rhs.map(|_| quote!(rhs,));
 
             let checked_name = Ident::new(&format!("checked_{}", op.name), Span::call_site());
-            let checked_comment = format!("Checked {}.", op.description);
+            let checked_comment = format!("Checked {}.", description);
+            let checked_body = match &float_value {
+                Some(value) => quote!(Self::new(#value)),
+                None => quote!(self.get().#checked_name(#rhs_value).and_then(Self::new)),
+            };
 
             tokens.extend(quote! {
                 #[doc = #checked_comment]
                 #[must_use]
                 #vis fn #checked_name(self, #rhs_type) -> ::core::option::Option<Self> {
-                    self.get().#checked_name(#rhs_value).and_then(Self::new)
+                    #checked_body
                 }
             });
 
+            // Operators whose right-hand side is the same type also get a version that takes
+            // anything convertible into the primitive, such as this or another bounded integer
+            // with the same repr. Fixed-point types skip it for multiplication and division, as
+            // those would need rescaling and the `_scaled` methods already take `Self`.
+            let rescaled = matches!(op.name, "mul" | "div" | "div_euclid");
+            let bounded_rhs =
+                op.rhs == Some("Self") && !(rescaled && self.options().scale.is_some());
+            let bounded_comment = format!(
+                "{}. `rhs` can be any value that converts into the underlying primitive, such as \
+                another bounded integer with the same repr.",
+                description,
+            );
+
+            if bounded_rhs {
+                let checked_bounded_name =
+                    Ident::new(&format!("checked_{}_bounded", op.name), Span::call_site());
+                let checked_bounded_comment = format!("Checked {}", bounded_comment);
+
+                tokens.extend(quote! {
+                    #[doc = #checked_bounded_comment]
+                    #[must_use]
+                    #vis fn #checked_bounded_name(
+                        self,
+                        rhs: impl ::core::convert::Into<#repr>,
+                    ) -> ::core::option::Option<Self> {
+                        self.#checked_name(rhs.into())
+                    }
+                });
+            }
+
             if repr_unsigned && op.on_unsigned == CheckedOnUnsigned::NoSaturating {
                 continue;
             }
-            if op.saturating {
+            if op.saturating && self.options().check.is_none() {
                 let saturating_name =
                     Ident::new(&format!("saturating_{}", op.name), Span::call_site());
-                let saturating_comment = format!("Saturing {}.", op.description);
+                let saturating_comment = format!("Saturating {}.", description);
+                let saturating_body = match &float_value {
+                    Some(value) => float_saturate(value.clone()),
+                    None => quote!(Self::new_saturating(self.get().#saturating_name(#rhs_value))),
+                };
 
                 tokens.extend(quote! {
                     #[doc = #saturating_comment]
                     #[must_use]
                     #vis fn #saturating_name(self, #rhs_type) -> Self {
-                        Self::new_saturating(self.get().#saturating_name(#rhs_value))
+                        #saturating_body
                     }
                 });
+
+                if bounded_rhs {
+                    let saturating_bounded_name = Ident::new(
+                        &format!("saturating_{}_bounded", op.name),
+                        Span::call_site(),
+                    );
+                    let saturating_bounded_comment = format!("Saturating {}", bounded_comment);
+
+                    tokens.extend(quote! {
+                        #[doc = #saturating_bounded_comment]
+                        #[must_use]
+                        #vis fn #saturating_bounded_name(
+                            self,
+                            rhs: impl ::core::convert::Into<#repr>,
+                        ) -> Self {
+                            self.#saturating_name(rhs.into())
+                        }
+                    });
+                }
             }
         }
     }
 
-    fn generate_fmt_traits(&self, tokens: &mut TokenStream) {
-        let ident = self.ident();
+    fn generate_scaled_methods(&self, tokens: &mut TokenStream) {
+        let vis = self.vis();
         let repr = self.repr();
+        let crate_location = self.crate_location();
+        // Products and quotients are computed exactly, so they only fail if the result overflows.
+        let mul_div = quote!(#crate_location::__private::mul_div);
+        let saturate = |result: TokenStream| {
+            quote! {
+                match #result {
+                    ::core::result::Result::Ok(n) => Self::new_saturating(n),
+                    ::core::result::Result::Err(true) => Self::MIN,
+                    ::core::result::Result::Err(false) => Self::MAX,
+                }
+            }
+        };
+        let saturating_product = saturate(quote!(#mul_div(self.get(), rhs.get(), Self::SCALE)));
+        let saturating_quotient = saturate(quote!(#mul_div(self.get(), Self::SCALE, rhs.get())));
 
-        for &fmt_trait in &[
-            "Binary", "Display", "LowerExp", "LowerHex", "Octal", "UpperExp", "UpperHex",
-        ] {
-            let fmt_trait = Ident::new(fmt_trait, Span::call_site());
+        tokens.extend(quote! {
+            /// Converts the fixed-point bounded integer to a float.
+            #[must_use]
+            #vis fn to_f64(self) -> ::core::primitive::f64 {
+                self.get() as ::core::primitive::f64 / Self::SCALE as ::core::primitive::f64
+            }
+            /// Converts a float to a fixed-point bounded integer, rounding it to a multiple of
+            /// `1 / SCALE` with the given rounding mode. Returns `None` if the float is NaN or the
+            /// result is out of range.
+            #[must_use]
+            #vis fn from_f64(
+                x: ::core::primitive::f64,
+                rounding: #crate_location::Rounding,
+            ) -> ::core::option::Option<Self> {
+                let n = #crate_location::__private::scale_f64(
+                    x,
+                    Self::SCALE as ::core::primitive::f64,
+                    rounding,
+                )?;
+                <#repr as ::core::convert::TryFrom<::core::primitive::i128>>::try_from(n)
+                    .ok()
+                    .and_then(Self::new)
+            }
+
+            /// Checked fixed-point multiplication, which divides the product by `SCALE`,
+            /// rounding towards zero.
+            #[must_use]
+            #vis fn checked_mul_scaled(self, rhs: Self) -> ::core::option::Option<Self> {
+                #mul_div(self.get(), rhs.get(), Self::SCALE).ok().and_then(Self::new)
+            }
+            /// Checked fixed-point division, which multiplies the dividend by `SCALE` first and
+            /// rounds towards zero. Returns `None` if `rhs` is 0.
+            #[must_use]
+            #vis fn checked_div_scaled(self, rhs: Self) -> ::core::option::Option<Self> {
+                if rhs.get() == 0 {
+                    return ::core::option::Option::None;
+                }
+                #mul_div(self.get(), Self::SCALE, rhs.get()).ok().and_then(Self::new)
+            }
+        });
+
+        if self.options().check.is_some() {
+            return;
+        }
+        tokens.extend(quote! {
+            /// Saturating fixed-point multiplication, which divides the product by `SCALE`,
+            /// rounding towards zero.
+            #[must_use]
+            #vis fn saturating_mul_scaled(self, rhs: Self) -> Self {
+                #saturating_product
+            }
+            /// Saturating fixed-point division, which multiplies the dividend by `SCALE` first and
+            /// rounds towards zero. Panics if `rhs` is 0.
+            #[must_use]
+            #vis fn saturating_div_scaled(self, rhs: Self) -> Self {
+                #saturating_quotient
+            }
+        });
+    }
+
+    fn generate_scaled_traits(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let crate_location = self.crate_location();
+        let digits = self.options().scale.as_ref().unwrap().1;
+        let sign_and_magnitude = if self.repr_unsigned() {
+            quote!((false, self.get() as ::core::primitive::u128))
+        } else {
+            quote! {
+                (self.get() < 0, (self.get() as ::core::primitive::i128).unsigned_abs())
+            }
+        };
 
+        if !self.options().skips("Display") {
             tokens.extend(quote! {
-                impl ::core::fmt::#fmt_trait for #ident {
+                impl ::core::fmt::Display for #ident {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        <#repr as ::core::fmt::#fmt_trait>::fmt(&self.get(), f)
+                        let (negative, magnitude) = #sign_and_magnitude;
+                        #crate_location::__private::fmt_scaled(f, negative, magnitude, #digits)
                     }
                 }
             });
         }
+
+        tokens.extend(quote! {
+            impl ::core::str::FromStr for #ident {
+                type Err = #crate_location::ParseDecimalError;
+
+                fn from_str(s: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    #crate_location::__private::parse_scaled(s, #digits, Self::new)
+                }
+            }
+        });
     }
 
-    #[cfg(feature = "serde")]
-    fn generate_serde(&self, tokens: &mut TokenStream) {
-        let ident = self.ident();
+    fn generate_values(&self, tokens: &mut TokenStream) {
+        let vis = self.vis();
         let repr = self.repr();
         let crate_location = self.crate_location();
-        let serde = quote!(#crate_location::serde);
 
-        tokens.extend(quote! {
-            impl #serde::Serialize for #ident {
-                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<
-                    <S as #serde::Serializer>::Ok,
-                    <S as #serde::Serializer>::Error,
-                >
-                where
-                    S: #serde::Serializer,
-                {
-                    <#repr as #serde::Serialize>::serialize(&self.get(), serializer)
+        // Values with gaps are found by walking the parts of the set, as offsets from `MIN`.
+        let values = match self.gaps() {
+            Some(values) => {
+                let parts = set_parts(values);
+                quote! {
+                    #crate_location::__private::set_values(#parts).map(|offset| {
+                        (Self::MIN_VALUE as ::core::primitive::u128).wrapping_add(offset) as #repr
+                    })
                 }
             }
-        });
+            None => quote!((Self::MIN_VALUE..=Self::MAX_VALUE)),
+        };
+        let body = if self.options().check.is_some() {
+            quote!(#values.filter_map(Self::new))
+        } else {
+            quote! {
+                // SAFETY: The values are all in range.
+                #values.map(|n| unsafe { Self::new_unchecked(n) })
+            }
+        };
 
         tokens.extend(quote! {
-            impl<'de> #serde::Deserialize<'de> for #ident {
-                fn deserialize<D>(deserializer: D) -> ::core::result::Result<
-                    Self,
-                    <D as #serde::Deserializer<'de>>::Error,
-                >
-                where
-                    D: #serde::Deserializer<'de>,
-                {
-                    let value = <#repr as #serde::Deserialize<'de>>::deserialize(deserializer)?;
-                    Self::new(value)
-                        .ok_or_else(|| {
-                            <<D as #serde::Deserializer<'de>>::Error as #serde::de::Error>::custom(
-                                ::core::format_args!(
-                                    "integer out of range, expected it to be between {} and {}",
-                                    Self::MIN_VALUE,
-                                    Self::MAX_VALUE
-                                )
-                            )
-                        })
-                }
+            /// Iterates over every value of the bounded integer in increasing order.
+            #vis fn values() -> impl ::core::iter::DoubleEndedIterator<Item = Self> {
+                #body
             }
         });
     }
 
-    fn generate_impl(&self, tokens: &mut TokenStream) {
+    fn generate_char_methods(&self, tokens: &mut TokenStream) {
+        let vis = self.vis();
+
+        tokens.extend(quote! {
+            /// Gets the next value of the bounded char, or `None` if `self` is `MAX`.
+            #[must_use]
+            #vis fn succ(self) -> ::core::option::Option<Self> {
+                (self.get()..=Self::MAX_VALUE).skip(1).find_map(Self::new)
+            }
+            /// Gets the previous value of the bounded char, or `None` if `self` is `MIN`.
+            #[must_use]
+            #vis fn pred(self) -> ::core::option::Option<Self> {
+                (Self::MIN_VALUE..=self.get()).rev().skip(1).find_map(Self::new)
+            }
+            /// Iterates over every value of the bounded char in increasing order.
+            #vis fn values() -> impl ::core::iter::DoubleEndedIterator<Item = Self> {
+                (Self::MIN_VALUE..=Self::MAX_VALUE).filter_map(Self::new)
+            }
+        });
+    }
+
+    fn generate_char_conversions(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let crate_location = self.crate_location();
+
+        tokens.extend(quote! {
+            impl ::core::convert::TryFrom<::core::primitive::char> for #ident {
+                type Error = #crate_location::TryFromCharError;
+
+                fn try_from(
+                    c: ::core::primitive::char,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    #crate_location::__private::try_from_char(c, Self::new)
+                }
+            }
+            impl ::core::str::FromStr for #ident {
+                type Err = #crate_location::ParseCharError;
+
+                fn from_str(s: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    #crate_location::__private::parse_char(s, Self::new)
+                }
+            }
+        });
+    }
+
+    fn generate_float_traits(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+
+        tokens.extend(quote! {
+            impl ::core::cmp::Eq for #ident {}
+            impl ::core::cmp::PartialOrd for #ident {
+                fn partial_cmp(
+                    &self,
+                    other: &Self,
+                ) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+                }
+            }
+            impl ::core::cmp::Ord for #ident {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    // Bounded floats are never NaN, so they can always be compared.
+                    <#repr as ::core::cmp::PartialOrd>::partial_cmp(&self.get(), &other.get())
+                        .unwrap()
+                }
+            }
+        });
+
+        if !self.options().skips("Hash") {
+            tokens.extend(quote! {
+                impl ::core::hash::Hash for #ident {
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        // `-0.0` and `0.0` are equal, so they must have the same hash.
+                        let value = if self.get() == 0.0 { 0.0 } else { self.get() };
+                        ::core::hash::Hash::hash(&value.to_bits(), state);
+                    }
+                }
+            });
+        }
+    }
+
+    fn generate_trait_impl(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+        let crate_location = self.crate_location();
+
+        tokens.extend(quote! {
+            impl #crate_location::BoundedInteger for #ident {
+                type Repr = #repr;
+
+                const MIN_VALUE: #repr = Self::MIN_VALUE;
+                const MAX_VALUE: #repr = Self::MAX_VALUE;
+                const MIN: Self = Self::MIN;
+                const MAX: Self = Self::MAX;
+                const RANGE: ::core::primitive::u128 = Self::RANGE;
+
+                unsafe fn new_unchecked(n: #repr) -> Self {
+                    Self::new_unchecked(n)
+                }
+                fn in_range(n: #repr) -> ::core::primitive::bool {
+                    Self::in_range(n)
+                }
+                fn new(n: #repr) -> ::core::option::Option<Self> {
+                    Self::new(n)
+                }
+                fn new_saturating(n: #repr) -> Self {
+                    Self::new_saturating(n)
+                }
+                fn new_wrapping(n: #repr) -> Self {
+                    Self::new_wrapping(n)
+                }
+                fn get(self) -> #repr {
+                    self.get()
+                }
+            }
+        });
+    }
+
+    fn generate_conversions(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+
+        tokens.extend(quote! {
+            impl ::core::convert::From<#ident> for #repr {
+                fn from(bounded: #ident) -> Self {
+                    bounded.get()
+                }
+            }
+            impl<'a> ::core::convert::From<&'a #ident> for #repr {
+                fn from(bounded: &'a #ident) -> Self {
+                    bounded.get()
+                }
+            }
+        });
+    }
+
+    fn generate_default(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let crate_location = self.crate_location();
+
+        let value = match &self.options().default {
+            None => return,
+            Some(DefaultValue::Zero) if self.options().float_repr => {
+                quote!(#crate_location::bounded!(#ident, 0.0))
+            }
+            Some(DefaultValue::Zero) => quote!(#crate_location::bounded!(#ident, 0)),
+            Some(DefaultValue::Min) => quote!(#ident::MIN),
+            Some(DefaultValue::Max) => quote!(#ident::MAX),
+            Some(DefaultValue::Value(value)) => {
+                quote_spanned!(value.span()=> #crate_location::bounded!(#ident, #value))
+            }
+        };
+
+        tokens.extend(quote! {
+            impl ::core::default::Default for #ident {
+                fn default() -> Self {
+                    #value
+                }
+            }
+        });
+    }
+
+    fn generate_fmt_traits(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+
+        if self.numeric_debug() && !self.options().skips("Debug") {
+            let name = ident.to_string();
+            tokens.extend(quote! {
+                impl ::core::fmt::Debug for #ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.debug_tuple(#name).field(&self.get()).finish()
+                    }
+                }
+            });
+        }
+
+        for &fmt_trait in FMT_TRAITS {
+            // `char` only implements `Display` of the formatting traits.
+            let char_skips = self.options().char_repr && fmt_trait != "Display";
+            let float_skips = self.options().float_repr && !FLOAT_FMT_TRAITS.contains(&fmt_trait);
+            // Fixed-point bounded integers are displayed as decimals instead.
+            let scaled_skips = self.options().scale.is_some() && fmt_trait == "Display";
+            if self.options().skips(fmt_trait) || char_skips || float_skips || scaled_skips {
+                continue;
+            }
+            let fmt_trait = Ident::new(fmt_trait, Span::call_site());
+
+            tokens.extend(quote! {
+                impl ::core::fmt::#fmt_trait for #ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        <#repr as ::core::fmt::#fmt_trait>::fmt(&self.get(), f)
+                    }
+                }
+            });
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn generate_serde(&self, tokens: &mut TokenStream) {
+        let ident = self.ident();
+        let repr = self.repr();
+        let crate_location = self.crate_location();
+        let serde = quote!(#crate_location::serde);
+
+        let char_repr = self.options().char_repr;
+        let message = match (self.gaps(), &self.options().check) {
+            (None, None) if self.options().float_repr => {
+                "invalid float, expected a number between {} and {}".to_owned()
+            }
+            (None, Some(check)) if self.options().float_repr => format!(
+                "invalid float, expected a number between {{}} and {{}} that passes `{}`",
+                quote!(#check),
+            ),
+            (None, None) if char_repr => {
+                "character out of range, expected it to be between {:?} and {:?}".to_owned()
+            }
+            (None, Some(check)) if char_repr => format!(
+                "invalid character, expected a character between {{:?}} and {{:?}} that passes \
+                `{}`",
+                quote!(#check),
+            ),
+            (None, None) => "integer out of range, expected it to be between {} and {}".to_owned(),
+            (_, None) => "invalid integer, expected one of the values between {} and {}".to_owned(),
+            (_, Some(check)) => format!(
+                "invalid integer, expected a value between {{}} and {{}} that passes `{}`",
+                quote!(#check),
+            ),
+        };
+
+        tokens.extend(quote! {
+            impl #serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<
+                    <S as #serde::Serializer>::Ok,
+                    <S as #serde::Serializer>::Error,
+                >
+                where
+                    S: #serde::Serializer,
+                {
+                    <#repr as #serde::Serialize>::serialize(&self.get(), serializer)
+                }
+            }
+        });
+
+        tokens.extend(quote! {
+            impl<'de> #serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<
+                    Self,
+                    <D as #serde::Deserializer<'de>>::Error,
+                >
+                where
+                    D: #serde::Deserializer<'de>,
+                {
+                    let value = <#repr as #serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    Self::new(value)
+                        .ok_or_else(|| {
+                            <<D as #serde::Deserializer<'de>>::Error as #serde::de::Error>::custom(
+                                ::core::format_args!(
+                                    #message,
+                                    Self::MIN_VALUE,
+                                    Self::MAX_VALUE
+                                )
+                            )
+                        })
+                }
+            }
+        });
+    }
+
+    fn generate_impl(&self, tokens: &mut TokenStream) {
         let mut inner_tokens = TokenStream::new();
 
         self.generate_consts(&mut inner_tokens);
         self.generate_base(&mut inner_tokens);
-        self.generate_operators(&mut inner_tokens);
-        self.generate_checked_operators(&mut inner_tokens);
+        if self.options().char_repr {
+            self.generate_char_methods(&mut inner_tokens);
+        } else if self.options().float_repr {
+            self.generate_checked_operators(&mut inner_tokens);
+        } else {
+            self.generate_values(&mut inner_tokens);
+            self.generate_operators(&mut inner_tokens);
+            self.generate_checked_operators(&mut inner_tokens);
+        }
+        if self.options().scale.is_some() {
+            self.generate_scaled_methods(&mut inner_tokens);
+        }
 
         let ident = self.ident();
         tokens.extend(quote!(impl #ident { #inner_tokens }));
 
-        self.generate_ops_traits(tokens);
+        if let Self::Struct {
+            options,
+            brace_token,
+            range,
+            ..
+        } = self
+        {
+            tokens.extend(quote_spanned! {brace_token.span=>
+                #[allow(clippy::absurd_extreme_comparisons)]
+                const _: () = ::core::assert!(
+                    #ident::MIN_VALUE <= #ident::MAX_VALUE,
+                    "the range of a bounded integer must not be empty",
+                );
+            });
+            if options.modular && range.0.is_some() {
+                tokens.extend(quote_spanned! {brace_token.span=>
+                    const _: () = ::core::assert!(
+                        #ident::MIN_VALUE == 0,
+                        "modular bounded integers must have a minimum of 0",
+                    );
+                });
+            }
+            if let Some(check) = &options.check {
+                tokens.extend(quote_spanned! {check.span()=>
+                    const _: () = ::core::assert!(
+                        #check(#ident::MIN_VALUE) && #check(#ident::MAX_VALUE),
+                        "the minimum and maximum of a bounded integer must pass its check",
+                    );
+                });
+            }
+        }
+
+        // Bounded chars have no arithmetic, but can be converted from chars and strings.
+        if self.options().char_repr {
+            self.generate_char_conversions(tokens);
+        } else if self.options().modular {
+            self.generate_modular_ops_traits(tokens);
+        } else {
+            self.generate_ops_traits(tokens);
+        }
+        // Floats aren't `Ord`, so bounded floats can't implement the trait but implement the
+        // comparison traits themselves instead. Bounded integers with a check don't have the
+        // saturating and wrapping constructors the trait requires.
+        if self.options().float_repr {
+            self.generate_float_traits(tokens);
+        } else if self.options().check.is_none() {
+            self.generate_trait_impl(tokens);
+        }
+        self.generate_conversions(tokens);
+        if self.options().scale.is_some() {
+            self.generate_scaled_traits(tokens);
+        }
+        self.generate_default(tokens);
         self.generate_fmt_traits(tokens);
         #[cfg(feature = "serde")]
         self.generate_serde(tokens);
@@ -563,13 +2040,18 @@ impl BoundedInteger {
             Self::Enum { attrs, .. } => attrs,
         }
     }
-    #[cfg(feature = "serde")]
     fn crate_location(&self) -> &Path {
         match self {
             Self::Struct { crate_location, .. } => crate_location,
             Self::Enum { crate_location, .. } => crate_location,
         }
     }
+    fn options(&self) -> &Options {
+        match self {
+            Self::Struct { options, .. } => options,
+            Self::Enum { options, .. } => options,
+        }
+    }
     fn repr(&self) -> &Path {
         match self {
             Self::Struct { repr, .. } => repr,
@@ -582,6 +2064,71 @@ impl BoundedInteger {
             Self::Enum { repr_unsigned, .. } => *repr_unsigned,
         }
     }
+    /// Whether `Debug` has to be implemented manually to print the value of an enum. Structs
+    /// already print their value with the derived implementation.
+    fn numeric_debug(&self) -> bool {
+        match self {
+            Self::Struct { .. } => false,
+            Self::Enum { options, .. } => options.numeric_debug.is_some(),
+        }
+    }
+    /// Gets the traits to derive, leaving out skipped ones and ones the user derives themselves.
+    fn derives(&self) -> Vec<Path> {
+        let options = self.options();
+        // Derive attributes have been checked to be valid while parsing.
+        let user_derives = self
+            .attrs()
+            .iter()
+            .filter(|attr| attr.path.is_ident("derive"))
+            .flat_map(|attr| {
+                attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let mut derives = Vec::<Path>::new();
+        let defaults = DERIVES
+            .iter()
+            .filter(|&&name| !options.skips(name))
+            .filter(|&&name| name != "Debug" || !self.numeric_debug())
+            .filter(|&&name| !options.float_repr || !FLOAT_MANUAL_DERIVES.contains(&name))
+            .map(|&name| Ident::new(name, Span::call_site()).into());
+        for derive in defaults.chain(options.derives.iter().cloned()) {
+            let name = derive.segments.last().unwrap().ident.to_string();
+            if !derives
+                .iter()
+                .chain(&user_derives)
+                .any(|path| is_derive(path, &name))
+            {
+                derives.push(derive);
+            }
+        }
+        derives
+    }
+    /// Gets the names of the enum variants in order of their values, or an empty list for
+    /// structs.
+    fn variants(&self) -> &[Ident] {
+        match self {
+            Self::Struct { .. } => &[],
+            Self::Enum { variants, .. } => variants,
+        }
+    }
+    /// Gets the name of the enum variant with the given value.
+    fn variant(&self, value: Int) -> &Ident {
+        match self {
+            Self::Struct { .. } => unreachable!("structs have no variants"),
+            Self::Enum {
+                values, variants, ..
+            } => &variants[values.index_of(value).unwrap() as usize],
+        }
+    }
+    /// Gets the values of the bounded integer if there are gaps between them.
+    fn gaps(&self) -> Option<&ValueSet> {
+        match self {
+            Self::Struct { values, .. } => values.as_ref(),
+            Self::Enum { values, .. } => Some(values).filter(|values| !values.is_range()),
+        }
+    }
     fn vis(&self) -> &Visibility {
         match self {
             Self::Struct { vis, .. } => vis,
@@ -596,170 +2143,1462 @@ impl BoundedInteger {
     }
 }
 
-impl Parse for BoundedInteger {
-    fn parse(input: ParseStream) -> parse::Result<Self> {
-        let mut attrs = input.call(Attribute::parse_outer)?;
-
-        let repr_pos = attrs
-            .iter()
-            .position(|attr| attr.path.is_ident("repr"))
-            .ok_or_else(|| input.error("no repr attribute on bounded integer"))?;
-        let repr: Path = attrs.remove(repr_pos).parse_args()?;
-        let repr_unsigned = repr.segments.last().unwrap().ident.to_string().starts_with('u');
-
-        let crate_location_pos = attrs
-            .iter()
-            .position(|attr| attr.path.is_ident("bounded_integer"));
-        let crate_location = crate_location_pos
-            .map(|crate_location_pos| -> parse::Result<_> {
-                let location: CrateLocation = syn::parse2(attrs.remove(crate_location_pos).tokens)?;
-                Ok(location.0)
-            })
-            .transpose()?
-            .unwrap_or_else(|| Path {
-                leading_colon: Some(Token![::](Span::call_site())),
-                segments: iter::once(PathSegment::from(Ident::new(
-                    "bounded_integer",
-                    Span::call_site(),
-                )))
-                .collect(),
-            });
+/// The values of a bounded integer as written in the macro: a range, or ranges and single values
+/// separated by `|`, where ranges can be followed by `step n`.
+struct ValuesExpr {
+    /// The ranges and values, each with its step.
+    parts: Vec<(Expr, Option<Expr>)>,
+}
 
-        let vis: Visibility = input.parse()?;
+impl ValuesExpr {
+    /// Gets the range if the values are a single range without a step.
+    fn as_range(&self) -> Option<&ExprRange> {
+        match &*self.parts {
+            [(Expr::Range(range), None)] => Some(range),
+            _ => None,
+        }
+    }
 
-        Ok(if input.peek(Token![struct]) {
-            let struct_token: Token![struct] = input.parse()?;
+    /// Iterates over the bounds of the ranges and the single values, with `None` for missing
+    /// bounds.
+    fn bounds(&self) -> impl Iterator<Item = Option<&Expr>> {
+        self.parts.iter().flat_map(|(expr, _)| match expr {
+            Expr::Range(range) => vec![range.from.as_deref(), range.to.as_deref()],
+            expr => vec![Some(expr)],
+        })
+    }
+}
 
-            let range;
-            #[allow(clippy::eval_order_dependence)]
-            let this = Self::Struct {
-                attrs,
-                crate_location,
-                repr,
-                repr_unsigned,
-                vis,
-                struct_token,
-                ident: input.parse()?,
-                brace_token: braced!(range in input),
-                range: {
-                    let range: ExprRange = range.parse()?;
-                    let limits = range.limits;
-                    Box::new((
-                        range.from.map(|from| *from),
-                        range.to.map(|to| match limits {
-                            RangeLimits::HalfOpen(_) => Expr::Verbatim(quote!(#to - 1)),
-                            RangeLimits::Closed(_) => *to,
-                        }),
-                    ))
-                },
+impl Parse for ValuesExpr {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let mut parts = Vec::new();
+        loop {
+            let expr: Expr = syn::parse2(parse_until_separator(input)?)?;
+            let step = if peek_step(input) {
+                let step_token = input.call(Ident::parse_any)?;
+                if !matches!(expr, Expr::Range(_)) {
+                    return Err(Error::new(step_token.span(), "only ranges can have a step"));
+                }
+                Some(syn::parse2(parse_until_separator(input)?)?)
+            } else {
+                None
             };
-            input.parse::<Option<Token![;]>>()?;
-            this
-        } else {
-            let range_tokens;
-            #[allow(clippy::eval_order_dependence)]
-            Self::Enum {
-                attrs,
-                crate_location,
-                repr,
-                repr_unsigned,
-                vis,
-                enum_token: input.parse()?,
-                ident: input.parse()?,
-                brace_token: braced!(range_tokens in input),
-                range: {
-                    let range: ExprRange = range_tokens.parse()?;
-                    let (from, to) =
-                        range
-                            .from
-                            .as_deref()
-                            .zip(range.to.as_deref())
-                            .ok_or_else(|| {
-                                Error::new_spanned(
-                                    &range,
-                                    "the bounds of an enum range must be closed",
-                                )
-                            })?;
-                    let (from, to) = (eval_expr(from)?, eval_expr(to)?);
-                    from..=if let RangeLimits::HalfOpen(_) = range.limits {
-                        to - 1
-                    } else {
-                        to
-                    }
-                },
-                semi_token: input.parse()?,
+            parts.push((expr, step));
+
+            if !input.peek(Token![|]) {
+                break;
             }
-        })
+            input.parse::<Token![|]>()?;
+        }
+        Ok(Self { parts })
     }
 }
 
-struct CrateLocation(Path);
-impl Parse for CrateLocation {
-    fn parse(input: ParseStream) -> parse::Result<Self> {
-        input.parse::<Token![=]>()?;
-        Ok(Self(input.parse::<Path>()?))
+impl ToTokens for ValuesExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (i, (expr, step)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                Token![|](Span::call_site()).to_tokens(tokens);
+            }
+            expr.to_tokens(tokens);
+            if let Some(step) = step {
+                tokens.extend(quote!(step #step));
+            }
+        }
     }
 }
 
-fn eval_expr(expr: &Expr) -> syn::Result<isize> {
-    Ok(match expr {
-        Expr::Lit(ExprLit { lit, .. }) => match lit {
-            Lit::Int(int) => int.base10_parse()?,
-            _ => {
-                return Err(Error::new_spanned(lit, "literal must be integer"));
-            }
-        },
-        Expr::Unary(ExprUnary { op, expr, .. }) => {
-            let expr = eval_expr(&expr)?;
-            match op {
-                UnOp::Not(_) => !expr,
-                UnOp::Neg(_) => -expr,
-                _ => {
-                    return Err(Error::new_spanned(op, "unary operator must be ! or -"));
-                }
-            }
-        }
-        Expr::Binary(ExprBinary {
-            left, op, right, ..
-        }) => {
-            let left = eval_expr(&left)?;
-            let right = eval_expr(&right)?;
-            match op {
-                BinOp::Add(_) => left + right,
-                BinOp::Sub(_) => left - right,
-                BinOp::Mul(_) => left * right,
-                BinOp::Div(_) => left / right,
-                BinOp::Rem(_) => left % right,
-                BinOp::BitXor(_) => left ^ right,
-                BinOp::BitAnd(_) => left & right,
-                BinOp::BitOr(_) => left | right,
-                _ => {
-                    return Err(Error::new_spanned(
-                        op,
-                        "operator not supported in this context",
-                    ));
+/// Takes the tokens up to the next `|`, `,` or `step` that isn't nested in a group.
+fn parse_until_separator(input: ParseStream) -> parse::Result<TokenStream> {
+    input.step(|cursor| {
+        let mut tokens = TokenStream::new();
+        let mut rest = *cursor;
+        while let Some((token, next)) = rest.token_tree() {
+            match &token {
+                TokenTree::Punct(punct) if punct.as_char() == '|' || punct.as_char() == ',' => {
+                    break
                 }
+                TokenTree::Ident(ident) if ident == "step" => break,
+                _ => {}
             }
+            tokens.extend(iter::once(token));
+            rest = next;
         }
-        Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
-            eval_expr(expr)?
-        }
-        _ => return Err(Error::new_spanned(expr, "expected simple expression")),
+        Ok((tokens, rest))
     })
 }
 
-fn enum_variant(i: isize) -> Ident {
-    Ident::new(
-        &*match i.cmp(&0) {
-            Ordering::Less => format!("N{}", i.abs()),
-            Ordering::Equal => "Z0".to_owned(),
-            Ordering::Greater => format!("P{}", i),
-        },
-        Span::call_site(),
-    )
+fn peek_step(input: ParseStream) -> bool {
+    input
+        .cursor()
+        .ident()
+        .is_some_and(|(ident, _)| ident == "step")
 }
 
-#[rustfmt::skip]
+/// The input of the function-like macro: items sharing the attributes of an optional header.
+struct BoundedIntegers(Vec<BoundedInteger>);
+
+impl Parse for BoundedIntegers {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let mut shared_attrs = input.call(Attribute::parse_inner)?;
+        for attr in &mut shared_attrs {
+            attr.style = AttrStyle::Outer;
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let mut attrs = shared_attrs.clone();
+            attrs.extend(input.call(Attribute::parse_outer)?);
+            items.push(BoundedInteger::parse_item(attrs, input)?);
+            if input.is_empty() {
+                break;
+            }
+        }
+        Ok(Self(items))
+    }
+}
+
+impl Parse for BoundedInteger {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        Self::parse_item(attrs, input)
+    }
+}
+
+impl BoundedInteger {
+    /// Parses a struct or enum item of the function-like macro following its attributes.
+    fn parse_item(attrs: Vec<Attribute>, input: ParseStream) -> parse::Result<Self> {
+        let vis: Visibility = input.parse()?;
+
+        if input.peek(Token![struct]) {
+            let struct_token: Token![struct] = input.parse()?;
+            let ident: Ident = input.parse()?;
+            let values_tokens;
+            let brace_token = braced!(values_tokens in input);
+            let values: ValuesExpr = values_tokens.parse()?;
+            input.parse::<Option<Token![;]>>()?;
+
+            Self::new_struct(attrs, None, vis, struct_token, ident, brace_token, values)
+        } else {
+            let enum_token: Token![enum] = input.parse()?;
+            let ident: Ident = input.parse()?;
+            let values_tokens;
+            let brace_token = braced!(values_tokens in input);
+            let values: ValuesExpr = values_tokens.parse()?;
+            let semi_token: Option<Token![;]> = input.parse()?;
+
+            let header = Header::parse(attrs, None, &ident, Some(&values))?;
+            let values = eval_values(&values, header.int_type()?)?;
+            Self::new_enum(
+                header,
+                vis,
+                enum_token,
+                ident,
+                brace_token,
+                values,
+                None,
+                semi_token,
+            )
+        }
+    }
+
+    /// Creates a bounded integer from the range given in the attribute and the struct or enum
+    /// item it is applied to.
+    fn from_attribute(args: TokenStream, item: Item) -> syn::Result<Self> {
+        let (values, options) = (|input: ParseStream| {
+            let values: ValuesExpr = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            Ok((values, input.parse::<TokenStream>()?))
+        })
+        .parse2(args)?;
+
+        let extra_attr = if options.is_empty() {
+            None
+        } else {
+            Some(parse_quote!(#[bounded_integer(#options)]))
+        };
+
+        match item {
+            Item::Struct(item) => {
+                let mut attrs = item.attrs;
+                attrs.extend(extra_attr);
+
+                if item.generics.lt_token.is_some() || item.generics.where_clause.is_some() {
+                    return Err(Error::new_spanned(
+                        item.generics,
+                        "bounded integers cannot be generic",
+                    ));
+                }
+                let (repr, brace_token) = match item.fields {
+                    Fields::Unit => (None, Brace(item.ident.span())),
+                    Fields::Unnamed(fields) => {
+                        let span = fields.paren_token.span;
+                        let mut fields = fields.unnamed.into_iter();
+                        let field = match (fields.next(), fields.next()) {
+                            (Some(field), None) => field,
+                            _ => {
+                                return Err(Error::new(
+                                    span,
+                                    "a bounded integer struct must have exactly one field",
+                                ))
+                            }
+                        };
+                        if !matches!(field.vis, Visibility::Inherited) {
+                            return Err(Error::new_spanned(
+                                field.vis,
+                                "the field of a bounded integer must be private",
+                            ));
+                        }
+                        match field.ty {
+                            Type::Path(TypePath { qself: None, path }) => (Some(path), Brace(span)),
+                            ty => {
+                                return Err(Error::new_spanned(
+                                    ty,
+                                    "the field of a bounded integer must be a primitive integer",
+                                ))
+                            }
+                        }
+                    }
+                    Fields::Named(fields) => {
+                        return Err(Error::new_spanned(
+                            fields,
+                            "expected a unit struct or a tuple struct with one field",
+                        ));
+                    }
+                };
+
+                Self::new_struct(
+                    attrs,
+                    repr,
+                    item.vis,
+                    item.struct_token,
+                    item.ident,
+                    brace_token,
+                    values,
+                )
+            }
+            Item::Enum(item) => {
+                let mut attrs = item.attrs;
+                attrs.extend(extra_attr);
+
+                if item.generics.lt_token.is_some() || item.generics.where_clause.is_some() {
+                    return Err(Error::new_spanned(
+                        item.generics,
+                        "bounded integers cannot be generic",
+                    ));
+                }
+                if !item.variants.is_empty() {
+                    return Err(Error::new_spanned(
+                        item.variants,
+                        "the variants of a bounded integer enum are generated from its range",
+                    ));
+                }
+
+                let header = Header::parse(attrs, None, &item.ident, Some(&values))?;
+                let values = eval_values(&values, header.int_type()?)?;
+                Self::new_enum(
+                    header,
+                    item.vis,
+                    item.enum_token,
+                    item.ident,
+                    item.brace_token,
+                    values,
+                    None,
+                    None,
+                )
+            }
+            item => Err(Error::new_spanned(item, "expected a struct or an enum")),
+        }
+    }
+
+    /// Creates a bounded integer from an enum with user-declared variants, as given to the derive
+    /// macro.
+    fn from_derive(input: DeriveInput) -> syn::Result<Self> {
+        let data = match input.data {
+            Data::Enum(data) => data,
+            Data::Struct(DataStruct { struct_token, .. }) => {
+                return Err(Error::new_spanned(
+                    struct_token,
+                    "BoundedInteger can only be derived for enums",
+                ))
+            }
+            Data::Union(DataUnion { union_token, .. }) => {
+                return Err(Error::new_spanned(
+                    union_token,
+                    "BoundedInteger can only be derived for enums",
+                ))
+            }
+        };
+        if input.generics.lt_token.is_some() || input.generics.where_clause.is_some() {
+            return Err(Error::new_spanned(
+                input.generics,
+                "bounded integers cannot be generic",
+            ));
+        }
+
+        let header = Header::parse(input.attrs, None, &input.ident, None)?;
+        let ty = header.int_type()?;
+
+        let mut variants = Vec::with_capacity(data.variants.len());
+        let mut next_value = Some(eval_expr(&parse_quote!(0), ty)?);
+        for variant in &data.variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new_spanned(
+                    &variant.fields,
+                    "the variants of a bounded integer enum cannot have fields",
+                ));
+            }
+            let value = match (&variant.discriminant, next_value) {
+                (Some((_, discriminant)), _) => eval_expr(discriminant, ty)?,
+                (None, Some(value)) => value,
+                (None, None) => {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        format!("enum discriminant overflowed the range of `{}`", ty),
+                    ))
+                }
+            };
+            variants.push((value, &variant.ident));
+            next_value = value.successor(ty);
+        }
+        variants.sort_by_key(|&(value, _)| value);
+
+        let (first, last) = match (variants.first(), variants.last()) {
+            (Some(&(first, _)), Some(&(last, _))) => (first, last),
+            _ => {
+                return Err(Error::new(
+                    data.brace_token.span,
+                    "a bounded integer enum must have at least one variant",
+                ))
+            }
+        };
+        for pair in variants.windows(2) {
+            let ((value, ident), (next_value, next_ident)) = (pair[0], pair[1]);
+            if value.successor(ty) != Some(next_value) {
+                return Err(Error::new(
+                    next_ident.span(),
+                    format!(
+                        "the discriminants of a bounded integer enum must be contiguous, but \
+                        `{}` is {} and the next value is {} (`{}`)",
+                        ident, value, next_value, next_ident,
+                    ),
+                ));
+            }
+        }
+        let variants = variants
+            .into_iter()
+            .map(|(_, ident)| ident.clone())
+            .collect();
+
+        Self::new_enum(
+            header,
+            input.vis,
+            data.enum_token,
+            input.ident,
+            data.brace_token,
+            ValueSet::from_range(first, last),
+            Some(variants),
+            None,
+        )
+    }
+
+    fn new_struct(
+        attrs: Vec<Attribute>,
+        repr: Option<Path>,
+        vis: Visibility,
+        struct_token: Token![struct],
+        ident: Ident,
+        brace_token: Brace,
+        values: ValuesExpr,
+    ) -> syn::Result<Self> {
+        let Header {
+            attrs,
+            crate_location,
+            options,
+            repr,
+            repr_unsigned,
+        } = Header::parse(attrs, repr, &ident, Some(&values))?;
+
+        if let Some(span) = options.naming_span() {
+            return Err(Error::new(
+                span,
+                "variant naming options can only be used with enums",
+            ));
+        }
+
+        let mut options = options;
+        if let Some(kind) = options.non_integer_kind() {
+            let range = values.as_range().ok_or_else(|| {
+                Error::new_spanned(
+                    &values,
+                    format!("bounded {} must have a single range of values", kind),
+                )
+            })?;
+            if options.modular {
+                return Err(Error::new(
+                    brace_token.span,
+                    format!("bounded {} cannot be modular", kind),
+                ));
+            }
+            if let (true, Some(DefaultValue::Zero)) = (options.char_repr, &options.default) {
+                return Err(Error::new(
+                    brace_token.span,
+                    "bounded chars have no zero to default to; use `default = MIN`, \
+                    `default = MAX` or `default = value` instead",
+                ));
+            }
+            let to = match (range.limits, range.to.as_deref()) {
+                (RangeLimits::HalfOpen(_), Some(_)) if options.float_repr => {
+                    return Err(Error::new_spanned(
+                        range,
+                        "the end of a range of floats must be inclusive",
+                    ));
+                }
+                (RangeLimits::HalfOpen(_), Some(to)) => Some(char_before(to, range)?),
+                (_, to) => to.cloned(),
+            };
+            let range = Box::new((range.from.as_deref().cloned(), to));
+            return Ok(Self::Struct {
+                attrs,
+                crate_location,
+                options,
+                repr,
+                repr_unsigned,
+                vis,
+                struct_token,
+                ident,
+                brace_token,
+                range,
+                values: None,
+            });
+        }
+
+        let range = match values.as_range() {
+            Some(range) => range,
+            None => {
+                let ty = IntType::from_path(&repr).ok_or_else(|| {
+                    Error::new_spanned(
+                        &repr,
+                        "the repr of a bounded integer with gaps or a step must be a primitive \
+                        integer type",
+                    )
+                })?;
+                let values = eval_values(&values, ty)?;
+                options.resolve_default(&values);
+                if options.modular && !values.is_range() {
+                    return Err(Error::new(
+                        brace_token.span,
+                        "modular bounded integers must have a single range of values",
+                    ));
+                }
+                let range = Box::new((Some(int_expr(values.min())), Some(int_expr(values.max()))));
+                return Ok(Self::Struct {
+                    attrs,
+                    crate_location,
+                    options,
+                    repr,
+                    repr_unsigned,
+                    vis,
+                    struct_token,
+                    ident,
+                    brace_token,
+                    range,
+                    values: Some(values).filter(|values| !values.is_range()),
+                });
+            }
+        };
+
+        if let Some(ty) = IntType::from_path(&repr) {
+            if let Some(range) = check_struct_range(range, ty)? {
+                options.resolve_default(&ValueSet::from_range(*range.start(), *range.end()));
+            }
+        }
+
+        if options.modular && range.from.is_none() && !repr_unsigned {
+            return Err(Error::new(
+                brace_token.span,
+                "modular bounded integers must have a minimum of 0",
+            ));
+        }
+
+        // The end is parenthesized, as it can be an expression that binds more loosely than `-`.
+        let limits = range.limits;
+        let range = Box::new((
+            range.from.as_deref().cloned(),
+            range.to.as_deref().map(|to| match limits {
+                RangeLimits::HalfOpen(_) => Expr::Verbatim(quote!((#to) - 1)),
+                RangeLimits::Closed(_) => to.clone(),
+            }),
+        ));
+
+        Ok(Self::Struct {
+            attrs,
+            crate_location,
+            options,
+            repr,
+            repr_unsigned,
+            vis,
+            struct_token,
+            ident,
+            brace_token,
+            range,
+            values: None,
+        })
+    }
+
+    /// Creates an enum bounded integer. `variants` holds the names of the variants if they have
+    /// been declared by the user, in order of their values.
+    #[allow(clippy::too_many_arguments)]
+    fn new_enum(
+        header: Header,
+        vis: Visibility,
+        enum_token: Token![enum],
+        ident: Ident,
+        brace_token: Brace,
+        values: ValueSet,
+        variants: Option<Vec<Ident>>,
+        semi_token: Option<Token![;]>,
+    ) -> syn::Result<Self> {
+        let ty = header.int_type()?;
+        let Header {
+            attrs,
+            crate_location,
+            options,
+            repr,
+            repr_unsigned,
+        } = header;
+
+        if let Some(check) = &options.check {
+            return Err(Error::new_spanned(
+                check,
+                "the `check` option can only be used with structs, as every variant of an enum is \
+                a value",
+            ));
+        }
+
+        let mut options = options;
+        options.resolve_default(&values);
+
+        if options.modular && !values.is_range() {
+            return Err(Error::new(
+                brace_token.span,
+                "modular bounded integers must have a single range of values",
+            ));
+        }
+        if options.modular && !values.min().is_zero() {
+            return Err(Error::new(
+                brace_token.span,
+                "modular bounded integers must have a minimum of 0",
+            ));
+        }
+
+        if variants.is_none() {
+            let max_variants = options.max_variants.unwrap_or(DEFAULT_MAX_VARIANTS);
+            if values.last_index() >= max_variants as u128 {
+                if !options.auto_form {
+                    // The full range of a 128-bit type has one more value than fits in a `u128`.
+                    let count = match values.last_index().checked_add(1) {
+                        Some(count) => count.to_string(),
+                        None => "340282366920938463463374607431768211456".to_owned(),
+                    };
+                    return Err(Error::new(
+                        brace_token.span,
+                        format!(
+                            "this enum would have {} variants, more than the limit of {}; use a \
+                            struct instead, add `#[bounded_integer(auto_form)]` to do so \
+                            automatically, or raise the limit with \
+                            `#[bounded_integer(max_variants = ...)]`",
+                            count, max_variants,
+                        ),
+                    ));
+                }
+                if let Some(span) = options.naming_span().or(options.numeric_debug) {
+                    return Err(Error::new(
+                        span,
+                        "variant naming options cannot be used when auto_form generates a struct",
+                    ));
+                }
+                return Ok(Self::Struct {
+                    attrs,
+                    crate_location,
+                    options,
+                    repr,
+                    repr_unsigned,
+                    vis,
+                    struct_token: Token![struct](enum_token.span),
+                    ident,
+                    brace_token,
+                    range: Box::new((Some(int_expr(values.min())), Some(int_expr(values.max())))),
+                    values: Some(values).filter(|values| !values.is_range()),
+                });
+            }
+        }
+
+        let variants = match variants {
+            Some(variants) => {
+                if let Some(span) = options.naming_span() {
+                    return Err(Error::new(
+                        span,
+                        "variant naming options cannot be used with declared variants",
+                    ));
+                }
+                variants
+            }
+            None => options.variant_names(&values, ty)?,
+        };
+
+        Ok(Self::Enum {
+            attrs,
+            crate_location,
+            options,
+            repr,
+            repr_unsigned,
+            vis,
+            enum_token,
+            ident,
+            brace_token,
+            values,
+            variants,
+            semi_token,
+        })
+    }
+}
+
+/// Evaluates the values of an enum bounded integer, or of a bounded integer with gaps or a step.
+fn eval_values(values: &ValuesExpr, ty: IntType) -> syn::Result<ValueSet> {
+    let parts = values
+        .parts
+        .iter()
+        .map(|(expr, step)| {
+            let range = match expr {
+                Expr::Range(range) => eval_range(range, ty)?,
+                expr => {
+                    let value = eval_expr(expr, ty)?;
+                    value..=value
+                }
+            };
+            let step = match step {
+                Some(step) => {
+                    let value = eval_expr(step, ty)?;
+                    if value.is_negative() || value.is_zero() {
+                        return Err(Error::new_spanned(step, "the step must be positive"));
+                    }
+                    value.unsigned_abs()
+                }
+                None => 1,
+            };
+            Ok((Part::new(*range.start(), *range.end(), step), expr.span()))
+        })
+        .collect::<syn::Result<_>>()?;
+    ValueSet::new(parts)
+}
+
+/// Evaluates a range whose bounds must be closed.
+fn eval_range(range: &ExprRange, ty: IntType) -> syn::Result<RangeInclusive<Int>> {
+    let (from, to) = range
+        .from
+        .as_deref()
+        .zip(range.to.as_deref())
+        .ok_or_else(|| {
+            Error::new_spanned(
+                range,
+                "the bounds of enum ranges and of ranges with gaps or a step must be closed",
+            )
+        })?;
+    inclusive_range(range, eval_expr(from, ty)?, eval_expr(to, ty)?)
+}
+
+/// Chooses the smallest primitive integer type that can hold every value and bound, preferring
+/// unsigned types. Bounds that are all character literals are held in a `char`, and bounds that
+/// are all float literals in an `f64`.
+fn smallest_repr(values: &ValuesExpr) -> syn::Result<Path> {
+    if values.bounds().all(|bound| bound.is_some_and(is_char_lit)) {
+        return Ok(parse_quote!(char));
+    }
+    if values.bounds().all(|bound| bound.is_some_and(is_float_lit)) {
+        return Ok(parse_quote!(f64));
+    }
+    let bounds = values
+        .bounds()
+        .collect::<Option<Vec<_>>>()
+        .filter(|bounds| bounds.iter().all(|bound| is_evaluable(bound)))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                values,
+                "no repr attribute on bounded integer, and it can't be chosen automatically as \
+                its bounds aren't all literals",
+            )
+        })?;
+    // Bounds too large for an `i128` must be unsigned. The end of a half-open range must fit in
+    // the type too, as it is written in the generated code.
+    let eval_bounds = |ty| {
+        bounds
+            .iter()
+            .map(|bound| eval_expr(bound, ty))
+            .collect::<syn::Result<Vec<_>>>()
+    };
+    let bounds = eval_bounds(IntType::I128)
+        .or_else(|error| eval_bounds(IntType::U128).map_err(|_| error))?;
+    let (min, max) = (bounds.iter().min().unwrap(), bounds.iter().max().unwrap());
+    let ty = IntType::smallest(&(*min..=*max));
+    Ok(Ident::new(&ty.to_string(), Span::call_site()).into())
+}
+
+/// Checks the range of a struct bounded integer if the macro can evaluate its bounds, returning
+/// the evaluated range. Bounds that can't be evaluated are checked by the compiler instead.
+fn check_struct_range(range: &ExprRange, ty: IntType) -> syn::Result<Option<RangeInclusive<Int>>> {
+    let eval_bound = |bound: Option<&Expr>, default| match bound {
+        Some(expr) if is_evaluable(expr) => eval_expr(expr, ty).map(Some),
+        Some(_) => Ok(None),
+        None => Ok(Some(default)),
+    };
+    let from = eval_bound(range.from.as_deref(), ty.min())?;
+    let to = eval_bound(range.to.as_deref(), ty.max())?;
+    match (from, to) {
+        (Some(from), Some(to)) => inclusive_range(range, from, to).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Converts the evaluated bounds of a range to an inclusive range, checking that it isn't empty.
+fn inclusive_range(range: &ExprRange, from: Int, to: Int) -> syn::Result<RangeInclusive<Int>> {
+    // Ranges without an end include the maximum value.
+    let half_open = matches!(range.limits, RangeLimits::HalfOpen(_)) && range.to.is_some();
+    if to < from || half_open && to == from {
+        return Err(Error::new_spanned(
+            range,
+            "the range of a bounded integer must not be empty",
+        ));
+    }
+    Ok(from..=if half_open {
+        to.add_offset(u128::MAX)
+    } else {
+        to
+    })
+}
+
+/// Gets the last char of a half-open range of chars from the end of the range, which must be a
+/// literal. Surrogates aren't chars, so the char before U+E000 is U+D7FF.
+fn char_before(end: &Expr, range: &ExprRange) -> syn::Result<Expr> {
+    let end = match end {
+        Expr::Lit(ExprLit {
+            lit: Lit::Char(end),
+            ..
+        }) => end.value(),
+        _ => {
+            return Err(Error::new_spanned(
+                end,
+                "the end of a half-open range of chars must be a character literal",
+            ))
+        }
+    };
+    let before = match end {
+        '\u{E000}' => Some('\u{D7FF}'),
+        end => (end as u32).checked_sub(1).and_then(char::from_u32),
+    };
+    let before = before.ok_or_else(|| {
+        Error::new_spanned(range, "the range of a bounded integer must not be empty")
+    })?;
+    Ok(Expr::Verbatim(
+        Literal::character(before).into_token_stream(),
+    ))
+}
+
+fn is_char_lit(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(ExprLit {
+            lit: Lit::Char(_),
+            ..
+        })
+    )
+}
+
+/// Checks whether an expression is a float literal, which may be negated.
+fn is_float_lit(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(_), ..
+        }) => true,
+        Expr::Unary(unary) => matches!(unary.op, syn::UnOp::Neg(_)) && is_float_lit(&unary.expr),
+        Expr::Group(group) => is_float_lit(&group.expr),
+        _ => false,
+    }
+}
+
+/// Creates the parts of a set of values as a slice of their starts, lasts and steps, which are
+/// offsets from the minimum.
+fn set_parts(values: &ValueSet) -> TokenStream {
+    let parts = values.offsets().map(|(start, last, step)| {
+        let start = Literal::u128_unsuffixed(start);
+        let last = Literal::u128_unsuffixed(last);
+        let step = Literal::u128_unsuffixed(step);
+        quote!((#start, #last, #step))
+    });
+    quote!(&[#(#parts),*])
+}
+
+/// Creates an unsuffixed integer literal expression.
+fn int_expr(value: Int) -> Expr {
+    let literal = Literal::u128_unsuffixed(value.unsigned_abs());
+    if value.is_negative() {
+        Expr::Verbatim(quote!(-#literal))
+    } else {
+        Expr::Verbatim(literal.into_token_stream())
+    }
+}
+
+/// The information held in the attributes of a bounded integer.
+struct Header {
+    /// The attributes that are forwarded to the generated item.
+    attrs: Vec<Attribute>,
+    crate_location: Path,
+    options: Options,
+    repr: Path,
+    repr_unsigned: bool,
+}
+
+impl Header {
+    /// Gets the primitive integer type of an enum's repr, in which its range is evaluated.
+    fn int_type(&self) -> syn::Result<IntType> {
+        if let Some(kind) = self.options.non_integer_kind() {
+            return Err(Error::new_spanned(
+                &self.repr,
+                format!("bounded {} must be structs", kind),
+            ));
+        }
+        IntType::from_path(&self.repr).ok_or_else(|| {
+            Error::new_spanned(
+                &self.repr,
+                "the repr of a bounded integer enum must be a primitive integer type",
+            )
+        })
+    }
+
+    /// Extracts the header from the attributes of an item. `repr` is the type of the field for a
+    /// tuple struct, `ident` is the name of the item and `values` are its values, if it has them
+    /// written out so that the repr can be chosen from them.
+    fn parse(
+        mut attrs: Vec<Attribute>,
+        repr: Option<Path>,
+        ident: &Ident,
+        values: Option<&ValuesExpr>,
+    ) -> syn::Result<Self> {
+        let repr_attr = take_attrs(&mut attrs, "repr").pop();
+        let repr: Option<Path> = match (repr_attr, repr) {
+            (Some(repr_attr), None) => {
+                Some(repr_attr.parse_args()?).filter(|repr: &Path| !repr.is_ident("auto"))
+            }
+            (None, Some(repr)) => Some(repr),
+            (Some(repr_attr), Some(_)) => {
+                return Err(Error::new_spanned(
+                    repr_attr,
+                    "the repr of a tuple struct bounded integer is given by its field",
+                ));
+            }
+            (None, None) => None,
+        };
+        let auto_repr = repr.is_none();
+        let repr = match (repr, values) {
+            (Some(repr), _) => repr,
+            (None, Some(values)) => smallest_repr(values)?,
+            (None, None) => {
+                return Err(Error::new(
+                    ident.span(),
+                    "no repr attribute on bounded integer",
+                ));
+            }
+        };
+
+        let mut options = Options::default();
+        for attr in take_attrs(&mut attrs, "bounded_integer") {
+            (|input: ParseStream| {
+                if input.peek(Token![=]) {
+                    options.crate_location = Some(input.parse::<CrateLocation>()?.0);
+                } else {
+                    let content;
+                    parenthesized!(content in input);
+                    options.parse_list(&content)?;
+                }
+                Ok(())
+            })
+            .parse2(attr.tokens)?;
+        }
+
+        let user_derives = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("derive"))
+            .map(|attr| attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated))
+            .collect::<syn::Result<Vec<_>>>()?;
+        // Derived enums declare their own variants, so their `#[default]` variant is in range.
+        let generated = values.is_some();
+        let mut derives = user_derives.iter().flatten().chain(&options.derives);
+        if let Some(default) = derives.find(|derive| generated && is_derive(derive, "Default")) {
+            return Err(Error::new_spanned(
+                default,
+                "deriving `Default` could create an out of range value; use \
+                `#[bounded_integer(default = ...)]` instead",
+            ));
+        }
+
+        for skipped in &options.skip {
+            if options
+                .derives
+                .iter()
+                .any(|derive| derive.is_ident(skipped))
+            {
+                return Err(Error::new(
+                    skipped.span(),
+                    format!("`{}` is both derived and skipped", skipped),
+                ));
+            }
+        }
+
+        if let (true, Some(check)) = (options.modular, &options.check) {
+            return Err(Error::new_spanned(
+                check,
+                "modular bounded integers cannot have a check, as their arithmetic has to be able \
+                to produce every value in range",
+            ));
+        }
+        if let (Overflow::Saturate, Some(check)) = (options.overflow, &options.check) {
+            return Err(Error::new_spanned(
+                check,
+                "bounded integers with a check cannot saturate, as a saturated value might not \
+                pass the check",
+            ));
+        }
+
+        options.char_repr = is_primitive(&repr, &["char"]);
+        options.float_repr = is_primitive(&repr, &["f32", "f64"]);
+        let repr_unsigned = match (IntType::from_path(&repr), &options.signed) {
+            (None, Some(signed)) if options.non_integer_kind().is_some() => {
+                return Err(Error::new(
+                    signed.span,
+                    format!(
+                        "the `signed` option cannot be used with `{}`",
+                        quote!(#repr)
+                    ),
+                ));
+            }
+            (None, None) if options.char_repr => true,
+            (None, None) if options.float_repr => false,
+            (Some(ty), Some(signed)) if signed.value != ty.is_signed() => {
+                return Err(Error::new(
+                    signed.span,
+                    format!("the `signed` option contradicts the repr `{}`", ty),
+                ));
+            }
+            (Some(ty), _) => !ty.is_signed(),
+            (None, Some(signed)) => !signed.value,
+            (None, None) => {
+                return Err(Error::new_spanned(
+                    &repr,
+                    format!(
+                        "`{}` is not a primitive integer type; if it is an alias of one, give its \
+                        signedness with `#[bounded_integer(signed = true)]` or \
+                        `#[bounded_integer(signed = false)]`",
+                        quote!(#repr),
+                    ),
+                ));
+            }
+        };
+
+        if let Some((scale, _)) = &options.scale {
+            if let Some(kind) = options.non_integer_kind() {
+                return Err(Error::new(
+                    scale.span(),
+                    format!("bounded {} cannot be scaled", kind),
+                ));
+            }
+            if options.modular {
+                return Err(Error::new(
+                    scale.span(),
+                    "modular bounded integers cannot be scaled",
+                ));
+            }
+            // The constant can be given before the scale, so it is only checked once both are.
+            if let Some(scale_const) = options.consts.iter().find(|c| c.name == "SCALE") {
+                return Err(Error::new(
+                    scale_const.name.span(),
+                    "fixed-point bounded integers already have a constant `SCALE`",
+                ));
+            }
+            if let Some(ty) = IntType::from_path(&repr) {
+                if scale.base10_parse::<u128>()? > ty.max().unsigned_abs() {
+                    return Err(Error::new(
+                        scale.span(),
+                        format!("the scale does not fit in the repr `{}`", ty),
+                    ));
+                }
+            }
+        }
+
+        let crate_location = options.crate_location.take().unwrap_or_else(|| Path {
+            leading_colon: Some(Token![::](Span::call_site())),
+            segments: iter::once(PathSegment::from(Ident::new(
+                "bounded_integer",
+                Span::call_site(),
+            )))
+            .collect(),
+        });
+
+        options.auto_repr = auto_repr;
+
+        Ok(Self {
+            attrs,
+            crate_location,
+            options,
+            repr,
+            repr_unsigned,
+        })
+    }
+}
+
+/// Options set through `#[bounded_integer(...)]` attributes.
+#[derive(Default)]
+struct Options {
+    /// The path to the `bounded_integer` crate, if it was given.
+    crate_location: Option<Path>,
+    /// The format of the names of generated enum variants with non-negative values.
+    variants: Option<LitStr>,
+    /// The format of the names of generated enum variants with negative values, which is given
+    /// their absolute value.
+    negative_variants: Option<LitStr>,
+    /// Names of generated enum variants that don't follow the format.
+    rename: Vec<(Expr, Ident)>,
+    /// Whether enums implement `Debug` by printing their value like structs do, instead of
+    /// printing the name of the variant. The span is that of the option.
+    numeric_debug: Option<Span>,
+    /// Whether arithmetic operators wrap modulo `RANGE`.
+    modular: bool,
+    /// What arithmetic operators do when their result is out of range.
+    overflow: Overflow,
+    /// Traits to derive in addition to the default ones.
+    derives: Vec<Path>,
+    /// The default derives and trait implementations to leave out.
+    skip: Vec<Ident>,
+    /// The value returned by the `Default` implementation, if there is one.
+    default: Option<DefaultValue>,
+    /// Named associated constants, with their attributes.
+    consts: Vec<NamedConst>,
+    /// Whether the repr was chosen by the macro. This is not set by an option but by leaving out
+    /// the repr or setting it to `auto`.
+    auto_repr: bool,
+    /// Whether the repr is `char`. Like `auto_repr`, this is set from the repr.
+    char_repr: bool,
+    /// Whether the repr is `f32` or `f64`, which is also set from the repr.
+    float_repr: bool,
+    /// Whether the repr is signed, if it was given because the repr isn't a primitive integer.
+    signed: Option<LitBool>,
+    /// The largest number of variants an enum may have, if it isn't the default.
+    max_variants: Option<usize>,
+    /// Whether enums with too many variants are generated as structs instead.
+    auto_form: bool,
+    /// A `const fn` that values have to pass in addition to being in range.
+    check: Option<Path>,
+    /// The value that represents 1 in fixed-point bounded integers, along with its number of
+    /// decimal digits.
+    scale: Option<(LitInt, u32)>,
+}
+
+/// The traits derived for every bounded integer unless they are skipped.
+const DERIVES: &[&str] = &[
+    "Debug",
+    "Hash",
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+];
+
+/// The derives that the generated code relies on, which cannot be skipped.
+const REQUIRED_DERIVES: &[&str] = &["Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord"];
+
+/// The formatting traits implemented by forwarding to the repr.
+const FMT_TRAITS: &[&str] = &[
+    "Binary", "Display", "LowerExp", "LowerHex", "Octal", "UpperExp", "UpperHex",
+];
+
+/// The formatting traits that floats implement.
+const FLOAT_FMT_TRAITS: &[&str] = &["Display", "LowerExp", "UpperExp"];
+
+/// The derives that can't be derived for floats, which are implemented manually for bounded
+/// floats.
+const FLOAT_MANUAL_DERIVES: &[&str] = &["Hash", "Eq", "PartialOrd", "Ord"];
+
+/// The names accepted by the `skip` option.
+const SKIPPABLE: &[&str] = &[
+    "Debug", "Hash", "ops", "Add", "Sub", "Mul", "Div", "Rem", "Neg", "fmt", "Binary", "Display",
+    "LowerExp", "LowerHex", "Octal", "UpperExp", "UpperHex",
+];
+
+/// The largest number of variants an enum may have when `max_variants` isn't given.
+const DEFAULT_MAX_VARIANTS: usize = 1 << 16;
+
+/// The value of a bounded integer's `Default` implementation.
+enum DefaultValue {
+    /// Zero, if it is in range. The implementation is left out if the macro can tell that it
+    /// isn't.
+    Zero,
+    Min,
+    Max,
+    Value(Box<Expr>),
+}
+
+/// An associated constant given with the `consts` option.
+struct NamedConst {
+    attrs: Vec<Attribute>,
+    name: Ident,
+    value: Expr,
+}
+
+/// The names of the associated constants that every bounded integer has.
+const RESERVED_CONSTS: &[&str] = &["MIN_VALUE", "MAX_VALUE", "MIN", "MAX", "RANGE"];
+
+/// The behaviour of the arithmetic operator traits on overflow.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum Overflow {
+    #[default]
+    Panic,
+    Saturate,
+}
+
+impl Options {
+    fn parse_list(&mut self, input: ParseStream) -> parse::Result<()> {
+        while !input.is_empty() {
+            let name = input.call(Ident::parse_any)?;
+            match &*name.to_string() {
+                "crate" => {
+                    input.parse::<Token![=]>()?;
+                    self.crate_location = Some(input.parse()?);
+                }
+                "variants" => {
+                    input.parse::<Token![=]>()?;
+                    self.variants = Some(parse_variant_format(input)?);
+                }
+                "negative_variants" => {
+                    input.parse::<Token![=]>()?;
+                    self.negative_variants = Some(parse_variant_format(input)?);
+                }
+                "rename" => {
+                    let content;
+                    parenthesized!(content in input);
+                    while !content.is_empty() {
+                        // Only accept (negated) literals so that `=` isn't parsed as assignment.
+                        let value = if content.peek(Token![-]) {
+                            Expr::Unary(content.parse()?)
+                        } else {
+                            Expr::Lit(content.parse()?)
+                        };
+                        content.parse::<Token![=]>()?;
+                        self.rename.push((value, content.parse()?));
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                "numeric_debug" => self.numeric_debug = Some(name.span()),
+                "modular" => self.modular = true,
+                "overflow" => {
+                    input.parse::<Token![=]>()?;
+                    let value: Ident = input.parse()?;
+                    self.overflow = match &*value.to_string() {
+                        "panic" => Overflow::Panic,
+                        "saturate" => Overflow::Saturate,
+                        _ => {
+                            return Err(Error::new(value.span(), "expected `panic` or `saturate`"))
+                        }
+                    };
+                }
+                "max_variants" => {
+                    input.parse::<Token![=]>()?;
+                    self.max_variants = Some(input.parse::<LitInt>()?.base10_parse()?);
+                }
+                "auto_form" => self.auto_form = true,
+                "default" => {
+                    self.default = Some(if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        let value: Expr = input.parse()?;
+                        if is_ident(&value, "MIN") {
+                            DefaultValue::Min
+                        } else if is_ident(&value, "MAX") {
+                            DefaultValue::Max
+                        } else {
+                            DefaultValue::Value(Box::new(value))
+                        }
+                    } else {
+                        DefaultValue::Zero
+                    });
+                }
+                "consts" => {
+                    let content;
+                    parenthesized!(content in input);
+                    while !content.is_empty() {
+                        let attrs = content.call(Attribute::parse_outer)?;
+                        let name: Ident = content.parse()?;
+                        let name_string = name.to_string();
+                        if RESERVED_CONSTS.contains(&&*name_string) {
+                            return Err(Error::new(
+                                name.span(),
+                                format!("bounded integers already have a constant `{}`", name),
+                            ));
+                        }
+                        if self.consts.iter().any(|c| c.name == name) {
+                            return Err(Error::new(
+                                name.span(),
+                                format!("the constant `{}` is given more than once", name),
+                            ));
+                        }
+                        content.parse::<Token![=]>()?;
+                        let value = content.parse()?;
+                        self.consts.push(NamedConst { attrs, name, value });
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                "derive" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let derives = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+                    self.derives.extend(derives);
+                }
+                "skip" => {
+                    let content;
+                    parenthesized!(content in input);
+                    for name in Punctuated::<Ident, Token![,]>::parse_terminated(&content)? {
+                        let name_string = name.to_string();
+                        if REQUIRED_DERIVES.contains(&&*name_string) {
+                            return Err(Error::new(
+                                name.span(),
+                                format!(
+                                    "`{}` is required by bounded integers and cannot be skipped",
+                                    name,
+                                ),
+                            ));
+                        }
+                        if !SKIPPABLE.contains(&&*name_string) {
+                            return Err(Error::new(
+                                name.span(),
+                                format!(
+                                    "cannot skip `{}`; expected one of `{}`",
+                                    name,
+                                    SKIPPABLE.join("`, `"),
+                                ),
+                            ));
+                        }
+                        self.skip.push(name);
+                    }
+                }
+                "signed" => {
+                    input.parse::<Token![=]>()?;
+                    self.signed = Some(input.parse()?);
+                }
+                "check" => {
+                    input.parse::<Token![=]>()?;
+                    self.check = Some(input.parse()?);
+                }
+                "scale" => {
+                    input.parse::<Token![=]>()?;
+                    let scale: LitInt = input.parse()?;
+                    let mut value = scale.base10_parse::<u128>()?;
+                    let mut digits = 0;
+                    while value > 1 && value % 10 == 0 {
+                        value /= 10;
+                        digits += 1;
+                    }
+                    if value != 1 {
+                        return Err(Error::new(scale.span(), "the scale must be a power of 10"));
+                    }
+                    self.scale = Some((scale, digits));
+                }
+                _ => return Err(Error::new(name.span(), "unknown bounded integer option")),
+            }
+            if self.modular && self.overflow != Overflow::Panic {
+                return Err(Error::new(
+                    name.span(),
+                    "modular bounded integers always wrap around on overflow",
+                ));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Leaves out a zero `Default` implementation if the evaluated values of the bounded integer
+    /// don't contain zero.
+    fn resolve_default(&mut self, values: &ValueSet) {
+        if let (Some(DefaultValue::Zero), false) = (&self.default, values.contains_zero()) {
+            self.default = None;
+        }
+    }
+
+    /// Gets the name of the kind of bounded value if the repr isn't an integer.
+    fn non_integer_kind(&self) -> Option<&'static str> {
+        if self.char_repr {
+            Some("chars")
+        } else if self.float_repr {
+            Some("floats")
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether a default derive or trait implementation is skipped, either by name or as
+    /// part of the `ops` or `fmt` groups.
+    fn skips(&self, name: &str) -> bool {
+        let group = if OPERATORS.iter().any(|op| op.trait_name == name) {
+            Some("ops")
+        } else if FMT_TRAITS.contains(&name) {
+            Some("fmt")
+        } else {
+            None
+        };
+        self.skip
+            .iter()
+            .any(|skipped| skipped == name || group.is_some_and(|group| skipped == group))
+    }
+
+    /// Gets the span of the first variant naming option, if any was given.
+    fn naming_span(&self) -> Option<Span> {
+        self.variants
+            .as_ref()
+            .or(self.negative_variants.as_ref())
+            .map(LitStr::span)
+            .or_else(|| self.rename.first().map(|(_, ident)| ident.span()))
+    }
+
+    /// Generates the names of the variants of an enum with the given values of the type `ty`.
+    fn variant_names(&self, values: &ValueSet, ty: IntType) -> syn::Result<Vec<Ident>> {
+        let variants = self.variants.as_ref().map(LitStr::value);
+        let negative_variants = self.negative_variants.as_ref().map(LitStr::value);
+
+        let mut names = values
+            .values()
+            .map(|i| {
+                let name = match (i.is_negative(), i.is_zero(), &variants) {
+                    (true, _, _) => {
+                        let format = negative_variants.as_deref().unwrap_or("N{}");
+                        format.replacen("{}", &i.unsigned_abs().to_string(), 1)
+                    }
+                    (false, true, None) => "Z0".to_owned(),
+                    (false, _, variants) => {
+                        let format = variants.as_deref().unwrap_or("P{}");
+                        format.replacen("{}", &i.to_string(), 1)
+                    }
+                };
+                Ident::new(&name, Span::call_site())
+            })
+            .collect::<Vec<_>>();
+
+        // Each name is reported at its rename if it has one, and otherwise at its format.
+        let mut spans = values
+            .values()
+            .map(|i| {
+                let format = match i.is_negative() {
+                    true => &self.negative_variants,
+                    false => &self.variants,
+                };
+                format.as_ref().map_or_else(Span::call_site, LitStr::span)
+            })
+            .collect::<Vec<_>>();
+        let mut renamed = vec![false; names.len()];
+
+        for (value, ident) in &self.rename {
+            let index = values
+                .index_of(eval_expr(value, ty)?)
+                .ok_or_else(|| Error::new_spanned(value, "renamed value is out of range"))?;
+            let index = index as usize;
+            if renamed[index] {
+                return Err(Error::new_spanned(
+                    value,
+                    "this value is renamed more than once",
+                ));
+            }
+            renamed[index] = true;
+            names[index] = ident.clone();
+            spans[index] = ident.span();
+        }
+
+        let mut order = (0..names.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| names[a].cmp(&names[b]));
+        for pair in order.windows(2) {
+            let (first, second) = (pair[0], pair[1]);
+            if names[first] == names[second] {
+                let span = if renamed[first] && !renamed[second] {
+                    spans[first]
+                } else {
+                    spans[second]
+                };
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "the variant name `{}` is given to more than one value",
+                        names[first]
+                    ),
+                ));
+            }
+        }
+
+        Ok(names)
+    }
+}
+
+/// Checks whether the path of a derive names the given trait.
+fn is_derive(path: &Path, name: &str) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
+
+/// Checks whether an expression is a path consisting of a single identifier.
+fn is_ident(expr: &Expr, ident: &str) -> bool {
+    matches!(expr, Expr::Path(ExprPath { qself: None, path, .. }) if path.is_ident(ident))
+}
+
+/// Parses a format for the names of enum variants, which must contain `{}` and give a valid
+/// identifier.
+fn parse_variant_format(input: ParseStream) -> parse::Result<LitStr> {
+    let format: LitStr = input.parse()?;
+    let value = format.value();
+    if !value.contains("{}") {
+        return Err(Error::new(
+            format.span(),
+            "variant format must contain `{}`",
+        ));
+    }
+    if syn::parse_str::<Ident>(&value.replacen("{}", "0", 1)).is_err() {
+        return Err(Error::new(
+            format.span(),
+            "variant format must produce valid identifiers",
+        ));
+    }
+    Ok(format)
+}
+
+/// Removes all the attributes with the given name from the list.
+fn take_attrs(attrs: &mut Vec<Attribute>, name: &str) -> Vec<Attribute> {
+    let (taken, kept) = attrs.drain(..).partition(|attr| attr.path.is_ident(name));
+    *attrs = kept;
+    taken
+}
+
+struct CrateLocation(Path);
+impl Parse for CrateLocation {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<Path>()?))
+    }
+}
+
+#[rustfmt::skip]
 const CHECKED_OPERATORS: &[CheckedOperator] = &[
     CheckedOperator::new("add"       , "integer addition"      , Some("Self"), true , CheckedOnUnsigned::All         ),
     CheckedOperator::new("sub"       , "integer subtraction"   , Some("Self"), true , CheckedOnUnsigned::All         ),
@@ -773,6 +3612,37 @@ const CHECKED_OPERATORS: &[CheckedOperator] = &[
     CheckedOperator::new("pow"       , "exponentiation"        , Some("u32") , true , CheckedOnUnsigned::All         ),
 ];
 
+/// Gets the value computed by a checked operator of bounded floats, if they have the operator.
+fn float_operation(name: &str) -> Option<TokenStream> {
+    Some(match name {
+        "add" => quote!(self.get() + rhs),
+        "sub" => quote!(self.get() - rhs),
+        "mul" => quote!(self.get() * rhs),
+        "div" => quote!(self.get() / rhs),
+        "rem" => quote!(self.get() % rhs),
+        "neg" => quote!(-self.get()),
+        "abs" => quote!(if self.get() < 0.0 {
+            -self.get()
+        } else {
+            self.get()
+        }),
+        _ => return None,
+    })
+}
+
+/// Saturates the result of an operation on bounded floats. Results that are NaN, such as the
+/// product of zero and infinity, give `MIN` rather than panicking like `new_saturating` does.
+fn float_saturate(value: TokenStream) -> TokenStream {
+    quote!({
+        let n = #value;
+        if n.is_nan() {
+            Self::MIN
+        } else {
+            Self::new_saturating(n)
+        }
+    })
+}
+
 #[derive(Eq, PartialEq)]
 enum CheckedOnUnsigned {
     All,
@@ -808,18 +3678,21 @@ impl CheckedOperator {
 
 #[rustfmt::skip]
 const OPERATORS: &[Operator] = &[
-    Operator { trait_name: "Add", method: "add", description: "add"           , bin: true , on_unsigned: true },
-    Operator { trait_name: "Sub", method: "sub", description: "subtract"      , bin: true , on_unsigned: true },
-    Operator { trait_name: "Mul", method: "mul", description: "multiply"      , bin: true , on_unsigned: true },
-    Operator { trait_name: "Div", method: "div", description: "divide"        , bin: true , on_unsigned: true },
-    Operator { trait_name: "Rem", method: "rem", description: "take remainder", bin: true , on_unsigned: true },
-    Operator { trait_name: "Neg", method: "neg", description: "negate"        , bin: false, on_unsigned: false},
+    Operator { trait_name: "Add", method: "add", description: "add"           , saturating: "saturating_add", bin: true , on_unsigned: true },
+    Operator { trait_name: "Sub", method: "sub", description: "subtract"      , saturating: "saturating_sub", bin: true , on_unsigned: true },
+    Operator { trait_name: "Mul", method: "mul", description: "multiply"      , saturating: "saturating_mul", bin: true , on_unsigned: true },
+    Operator { trait_name: "Div", method: "div", description: "divide"        , saturating: "saturating_div", bin: true , on_unsigned: true },
+    Operator { trait_name: "Rem", method: "rem", description: "take remainder", saturating: "wrapping_rem"  , bin: true , on_unsigned: true },
+    Operator { trait_name: "Neg", method: "neg", description: "negate"        , saturating: "saturating_neg", bin: false, on_unsigned: false},
 ];
 
 struct Operator {
     trait_name: &'static str,
     method: &'static str,
     description: &'static str,
+    /// The primitive method that implements the operator when it saturates. Remainder can never
+    /// overflow mathematically, so it uses the wrapping version that only wraps `MIN % -1` to 0.
+    saturating: &'static str,
     bin: bool,
     on_unsigned: bool,
 }
@@ -877,58 +3750,302 @@ fn binop_trait_variations<B: ToTokens>(
     });
 }
 
-fn unop_trait_variations(
-    trait_name: &impl ToTokens,
-    method: &impl ToTokens,
-    lhs: &impl ToTokens,
-    body: &impl ToTokens,
-    tokens: &mut TokenStream,
-) {
-    tokens.extend(quote! {
-        impl ::core::ops::#trait_name for #lhs {
-            type Output = #lhs;
-            fn #method(self) -> Self::Output {
-                #body
-            }
+fn unop_trait_variations(
+    trait_name: &impl ToTokens,
+    method: &impl ToTokens,
+    lhs: &impl ToTokens,
+    body: &impl ToTokens,
+    tokens: &mut TokenStream,
+) {
+    tokens.extend(quote! {
+        impl ::core::ops::#trait_name for #lhs {
+            type Output = #lhs;
+            fn #method(self) -> Self::Output {
+                #body
+            }
+        }
+        impl<'a> ::core::ops::#trait_name for &'a #lhs {
+            type Output = #lhs;
+            fn #method(self) -> Self::Output {
+                <#lhs as ::core::ops::#trait_name>::#method(*self)
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse2;
+
+    fn assert_result(
+        f: impl FnOnce(&BoundedInteger, &mut TokenStream),
+        input: TokenStream,
+        expected: TokenStream,
+    ) {
+        let mut result = TokenStream::new();
+        f(&parse2::<BoundedInteger>(input).unwrap(), &mut result);
+        assert_eq!(result.to_string(), expected.to_string());
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_tokens() {
+        assert_result(
+            BoundedInteger::generate_item,
+            quote! {
+                #[repr(isize)]
+                pub(crate) enum Nibble { -8..6+2 }
+            },
+            quote! {
+                #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+                #[repr(isize)]
+                pub(crate) enum Nibble {
+                    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+                }
+            },
+        );
+
+        assert_result(
+            BoundedInteger::generate_item,
+            quote! {
+                #[repr(u16)]
+                enum Nibble { 3..=7 };
+            },
+            quote! {
+                #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+                #[repr(u16)]
+                enum Nibble {
+                    P3 = 3, P4, P5, P6, P7
+                };
+            },
+        );
+
+        assert_result(
+            BoundedInteger::generate_item,
+            quote! {
+                #[repr(i8)]
+                pub struct S { -3..2 }
+            },
+            quote! {
+                #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+                pub struct S(i8);
+            },
+        );
+    }
+
+    fn generate(bounded_integer: &BoundedInteger) -> String {
+        let mut result = TokenStream::new();
+        bounded_integer.generate_item(&mut result);
+        bounded_integer.generate_impl(&mut result);
+        result.to_string()
+    }
+
+    /// Gets the message of an error that is expected, so that tests can check that an input is
+    /// rejected for the right reason.
+    fn error_message<T>(result: syn::Result<T>) -> String {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    /// Asserts that parsing a bounded integer from the input fails with the given message.
+    #[track_caller]
+    fn assert_error(input: TokenStream, message: &str) {
+        assert_eq!(error_message(parse2::<BoundedInteger>(input)), message);
+    }
+
+    #[test]
+    fn test_attribute_form() {
+        let cases = [
+            (
+                quote!(-8..8),
+                quote! {
+                    /// Documentation.
+                    #[repr(i8)]
+                    pub struct S;
+                },
+                quote! {
+                    /// Documentation.
+                    #[repr(i8)]
+                    pub struct S { -8..8 }
+                },
+            ),
+            (
+                quote!(..=5, overflow = saturate),
+                quote!(
+                    struct S(u16);
+                ),
+                quote! {
+                    #[bounded_integer(overflow = saturate)]
+                    #[repr(u16)]
+                    struct S { ..=5 }
+                },
+            ),
+            (
+                quote!(0..12),
+                quote! {
+                    #[bounded_integer(modular)]
+                    #[bounded_integer = crate]
+                    #[repr(u8)]
+                    pub(crate) enum S {}
+                },
+                quote! {
+                    #[bounded_integer(modular)]
+                    #[bounded_integer = crate]
+                    #[repr(u8)]
+                    pub(crate) enum S { 0..12 }
+                },
+            ),
+        ];
+
+        for (args, item, expected) in cases.iter().cloned() {
+            let from_attribute =
+                BoundedInteger::from_attribute(args, parse2(item).unwrap()).unwrap();
+            let from_macro = parse2::<BoundedInteger>(expected).unwrap();
+            assert_eq!(generate(&from_attribute), generate(&from_macro));
+        }
+
+        let errors = [
+            (
+                quote!(0..5),
+                quote!(struct S(pub u8);),
+                "the field of a bounded integer must be private",
+            ),
+            (
+                quote!(0..5),
+                quote!(struct S(u8, u8);),
+                "a bounded integer struct must have exactly one field",
+            ),
+            (
+                quote!(0..5),
+                quote!(struct S { x: u8 }),
+                "expected a unit struct or a tuple struct with one field",
+            ),
+            (
+                quote!(0..5),
+                quote!(#[repr(u8)] struct S(u8);),
+                "the repr of a tuple struct bounded integer is given by its field",
+            ),
+            (
+                quote!(0..N),
+                quote!(struct S;),
+                "no repr attribute on bounded integer, and it can't be chosen automatically as its \
+                bounds aren't all literals",
+            ),
+            (
+                quote!(0..5),
+                quote!(#[repr(u8)] enum S { A }),
+                "the variants of a bounded integer enum are generated from its range",
+            ),
+            (quote!(0..5), quote!(fn s() {}), "expected a struct or an enum"),
+        ];
+        for (args, item, message) in errors.iter().cloned() {
+            let result = BoundedInteger::from_attribute(args, parse2(item).unwrap());
+            assert_eq!(error_message(result), message);
         }
-        impl<'a> ::core::ops::#trait_name for &'a #lhs {
-            type Output = #lhs;
-            fn #method(self) -> Self::Output {
-                <#lhs as ::core::ops::#trait_name>::#method(*self)
+    }
+
+    #[test]
+    fn test_derive() {
+        let derived = BoundedInteger::from_derive(
+            parse2(quote! {
+                #[repr(i8)]
+                enum S { B = 0, C, A = -1 }
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        match derived {
+            BoundedInteger::Enum {
+                values, variants, ..
+            } => {
+                assert_eq!(
+                    values,
+                    ValueSet::from_range(Int::Signed(-1), Int::Signed(1))
+                );
+                let variants = variants.iter().map(Ident::to_string).collect::<Vec<_>>();
+                assert_eq!(variants, ["A", "B", "C"]);
             }
+            BoundedInteger::Struct { .. } => panic!("derived a struct"),
         }
-    });
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use syn::parse2;
-
-    fn assert_result(
-        f: impl FnOnce(&BoundedInteger, &mut TokenStream),
-        input: TokenStream,
-        expected: TokenStream,
-    ) {
-        let mut result = TokenStream::new();
-        f(&parse2::<BoundedInteger>(input).unwrap(), &mut result);
-        assert_eq!(result.to_string(), expected.to_string());
+        let errors = [
+            (
+                quote!(
+                    #[repr(u8)]
+                    enum S {
+                        A,
+                        C = 2,
+                    }
+                ),
+                "the discriminants of a bounded integer enum must be contiguous, but `A` is 0 and \
+                the next value is 2 (`C`)",
+            ),
+            (
+                quote!(
+                    #[repr(u8)]
+                    enum S {
+                        A = 1,
+                        B = 1,
+                    }
+                ),
+                "the discriminants of a bounded integer enum must be contiguous, but `A` is 1 and \
+                the next value is 1 (`B`)",
+            ),
+            (
+                quote!(
+                    #[repr(u8)]
+                    enum S {
+                        A(u8),
+                    }
+                ),
+                "the variants of a bounded integer enum cannot have fields",
+            ),
+            (
+                quote!(
+                    #[repr(u8)]
+                    enum S {}
+                ),
+                "a bounded integer enum must have at least one variant",
+            ),
+            (
+                quote!(
+                    enum S {
+                        A,
+                    }
+                ),
+                "no repr attribute on bounded integer",
+            ),
+            (
+                quote!(
+                    #[repr(u8)]
+                    struct S;
+                ),
+                "BoundedInteger can only be derived for enums",
+            ),
+        ];
+        for (input, message) in errors.iter().cloned() {
+            let result = BoundedInteger::from_derive(parse2(input).unwrap());
+            assert_eq!(error_message(result), message);
+        }
     }
 
-    #[cfg(test)]
     #[test]
-    fn test_tokens() {
+    fn test_variant_naming() {
         assert_result(
             BoundedInteger::generate_item,
             quote! {
-                #[repr(isize)]
-                pub(crate) enum Nibble { -8..6+2 }
+                #[repr(i8)]
+                #[bounded_integer(variants = "V{}", rename(1 = One, -1 = MinusOne))]
+                enum S { -2..=2 }
             },
             quote! {
                 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-                #[repr(isize)]
-                pub(crate) enum Nibble {
-                    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+                #[repr(i8)]
+                enum S {
+                    N2 = -2, MinusOne, V0, One, V2
                 }
             },
         );
@@ -936,28 +4053,703 @@ mod tests {
         assert_result(
             BoundedInteger::generate_item,
             quote! {
-                #[repr(u16)]
-                enum Nibble { 3..=7 };
+                #[repr(i8)]
+                #[bounded_integer(negative_variants = "Minus{}", numeric_debug)]
+                enum S { -1..=1 }
+            },
+            quote! {
+                #[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+                #[repr(i8)]
+                enum S {
+                    Minus1 = -1, Z0, P1
+                }
+            },
+        );
+
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(variants = "V")] enum S { 0..2 }),
+            "variant format must contain `{}`",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(variants = "{}V")] enum S { 0..2 }),
+            "variant format must produce valid identifiers",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(rename(2 = Two))] enum S { 0..2 }),
+            "renamed value is out of range",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(variants = "V{}")] struct S { 0..2 }),
+            "variant naming options can only be used with enums",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(rename(0 = P1))] enum S { 0..2 }),
+            "the variant name `P1` is given to more than one value",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(rename(0 = A, 1 = A))] enum S { 0..2 }),
+            "the variant name `A` is given to more than one value",
+        );
+        assert_error(
+            quote! {
+                #[repr(i8)]
+                #[bounded_integer(variants = "V{}", negative_variants = "V{}")]
+                enum S { -1..=1 }
+            },
+            "the variant name `V1` is given to more than one value",
+        );
+        assert_error(
+            quote!(#[repr(i8)] #[bounded_integer(rename(1 = A, 1 = B))] enum S { 0..2 }),
+            "this value is renamed more than once",
+        );
+    }
+
+    #[test]
+    fn test_large_enum() {
+        let error = parse2::<BoundedInteger>(quote! {
+            #[repr(i8)]
+            #[bounded_integer(max_variants = 4)]
+            enum S { -2..=2 }
+        })
+        .err()
+        .unwrap();
+        assert!(error
+            .to_string()
+            .contains("5 variants, more than the limit of 4"));
+        let error = error_message(parse2::<BoundedInteger>(quote! {
+            #[repr(u128)]
+            enum S { 0..=u128::MAX }
+        }));
+        assert!(error.contains("340282366920938463463374607431768211456 variants"));
+
+        assert!(parse2::<BoundedInteger>(quote! {
+            #[repr(i8)]
+            #[bounded_integer(max_variants = 5)]
+            enum S { -2..=2 }
+        })
+        .is_ok());
+
+        assert_result(
+            BoundedInteger::generate_item,
+            quote! {
+                #[repr(u32)]
+                #[bounded_integer(auto_form)]
+                pub enum S { 0..=4_000_000 }
             },
             quote! {
                 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-                #[repr(u16)]
-                enum Nibble {
-                    P3 = 3, P4, P5, P6, P7
-                };
+                pub struct S(u32);
             },
         );
+        let mut tokens = TokenStream::new();
+        parse2::<BoundedInteger>(quote! {
+            #[repr(i8)]
+            #[bounded_integer(max_variants = 2, auto_form)]
+            enum S { -2..2 }
+        })
+        .unwrap()
+        .generate_consts(&mut tokens);
+        let tokens = tokens.to_string();
+        assert!(tokens.contains("const MIN_VALUE : i8 = - 2 ;"));
+        assert!(tokens.contains("const MAX : Self = Self (Self :: MAX_VALUE) ;"));
+
+        let naming = [
+            quote!(variants = "V{}"),
+            quote!(rename(0 = Zero)),
+            quote!(numeric_debug),
+        ];
+        for options in &naming {
+            assert_error(
+                quote! {
+                    #[repr(i8)]
+                    #[bounded_integer(max_variants = 2, auto_form, #options)]
+                    enum S { -2..2 }
+                },
+                "variant naming options cannot be used when auto_form generates a struct",
+            );
+        }
+    }
+
+    #[test]
+    fn test_wide_enum() {
+        let mut tokens = TokenStream::new();
+        parse2::<BoundedInteger>(quote! {
+            #[repr(u128)]
+            enum S { !0 - 1..=!0 }
+        })
+        .unwrap()
+        .generate_consts(&mut tokens);
+        assert!(tokens
+            .to_string()
+            .contains("const MAX_VALUE : u128 = 340282366920938463463374607431768211455 ;"));
+
+        assert_error(
+            quote!(#[repr(u8)] enum S { 0..=256 }),
+            "literal out of range for `u8`",
+        );
+        assert_error(
+            quote!(#[repr(i8)] enum S { 0..1 << 8 }),
+            "shift amount must be less than the 8 bits of `i8`",
+        );
+        assert_error(
+            quote!(#[repr(u16)] enum S { 0..1 / 0 }),
+            "attempt to divide by zero",
+        );
+        assert_error(
+            quote!(#[repr(MyInt)] enum S { 0..1 }),
+            "`MyInt` is not a primitive integer type; if it is an alias of one, give its \
+            signedness with `#[bounded_integer(signed = true)]` or \
+            `#[bounded_integer(signed = false)]`",
+        );
+
+        let input = parse2::<DeriveInput>(quote! {
+            #[repr(u8)]
+            enum S { A = 254, B, C }
+        })
+        .unwrap();
+        let error = error_message(BoundedInteger::from_derive(input));
+        assert_eq!(error, "enum discriminant overflowed the range of `u8`");
+    }
+
+    #[test]
+    fn test_range_checks() {
+        let valid = [
+            quote!(#[repr(u8)] struct S { 0..=255 }),
+            quote!(#[repr(u8)] struct S { 5.. }),
+            quote!(#[repr(i8)] struct S { ..=-128 }),
+            quote!(#[repr(u8)] struct S { 5..N }),
+            quote!(#[repr(MyInt)] #[bounded_integer(signed = true)] struct S { 10..5 }),
+            quote!(#[repr(i8)] enum S { 0..=0 }),
+        ];
+        for input in valid.iter().cloned() {
+            parse2::<BoundedInteger>(input).unwrap();
+        }
+
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..300 }),
+            "literal out of range for `u8`",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 10..5 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 5..5 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(#[repr(i8)] struct S { 0..=-1 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(#[repr(i8)] struct S { i8::MAX..0 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..u16::MAX }),
+            "this expression overflows the range of `u8`",
+        );
+        assert_error(
+            quote!(#[repr(i8)] enum S { 3..=2 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(#[repr(i8)] enum S { 0..0 }),
+            "the range of a bounded integer must not be empty",
+        );
+    }
+
+    #[test]
+    fn test_repr() {
+        let valid = [
+            quote!(#[repr(usize)] struct S { 0..5 }),
+            quote!(#[repr(core::primitive::u8)] struct S { 0..5 }),
+            quote!(#[repr(::std::primitive::i8)] enum S { -1..5 }),
+            quote!(#[repr(Word)] #[bounded_integer(signed = false)] struct S { 0..5 }),
+            quote!(#[repr(u8)] #[bounded_integer(signed = false)] struct S { 0..5 }),
+        ];
+        for input in valid.iter().cloned() {
+            parse2::<BoundedInteger>(input).unwrap();
+        }
+
+        assert_error(
+            quote!(#[repr(C)] struct S { 0..5 }),
+            "`C` is not a primitive integer type; if it is an alias of one, give its signedness \
+            with `#[bounded_integer(signed = true)]` or `#[bounded_integer(signed = false)]`",
+        );
+        assert_error(
+            quote!(#[repr(Word)] struct S { 0..5 }),
+            "`Word` is not a primitive integer type; if it is an alias of one, give its signedness \
+            with `#[bounded_integer(signed = true)]` or `#[bounded_integer(signed = false)]`",
+        );
+        assert_error(
+            quote!(#[repr(ucustom)] struct S { 0..5 }),
+            "`ucustom` is not a primitive integer type; if it is an alias of one, give its \
+            signedness with `#[bounded_integer(signed = true)]` or \
+            `#[bounded_integer(signed = false)]`",
+        );
+        assert_error(
+            quote!(#[repr(my::u8)] struct S { 0..5 }),
+            "`my :: u8` is not a primitive integer type; if it is an alias of one, give its \
+            signedness with `#[bounded_integer(signed = true)]` or \
+            `#[bounded_integer(signed = false)]`",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(signed = true)] struct S { 0..5 }),
+            "the `signed` option contradicts the repr `u8`",
+        );
+        assert_error(
+            quote!(#[repr(Word)] #[bounded_integer(signed = false)] enum S { 0..5 }),
+            "the repr of a bounded integer enum must be a primitive integer type",
+        );
+    }
+
+    #[test]
+    fn test_auto_repr() {
+        let cases = [
+            (quote!(struct S { 0..=255 }), "u8"),
+            (quote!(#[repr(auto)] struct S { 0..256 }), "u16"),
+            (quote!(enum S { -128..=127 }), "i8"),
+            (quote!(enum S { -1..=128 }), "i16"),
+            (quote!(struct S { 0..=u32::MAX }), "u32"),
+            (quote!(struct S { i64::MIN..=0 }), "i64"),
+            (quote!(struct S { 1..=u128::MAX }), "u128"),
+            (quote!(struct S { i128::MIN..0 }), "i128"),
+        ];
+        for (input, expected) in cases.iter().cloned() {
+            let bounded_integer = parse2::<BoundedInteger>(input).unwrap();
+            assert!(bounded_integer.repr().is_ident(expected));
+            assert!(bounded_integer.options().auto_repr);
+        }
+
+        assert_error(
+            quote!(struct S { 0.. }),
+            "no repr attribute on bounded integer, and it can't be chosen automatically as its \
+            bounds aren't all literals",
+        );
+        assert_error(
+            quote!(struct S { N..10 }),
+            "no repr attribute on bounded integer, and it can't be chosen automatically as its \
+            bounds aren't all literals",
+        );
+        assert_error(
+            quote!(#[repr(auto)] struct S { 10..0 }),
+            "the range of a bounded integer must not be empty",
+        );
+        assert_error(
+            quote!(enum S { 0..u128::MAX + 1 }),
+            "this expression overflows the range of `i128`",
+        );
+    }
 
+    #[test]
+    fn test_derives_and_skip() {
         assert_result(
             BoundedInteger::generate_item,
             quote! {
+                #[repr(u8)]
+                #[derive(Debug, serde::Serialize)]
+                #[bounded_integer(derive(MyTrait, Clone), skip(Hash, ops))]
+                struct S { 0..5 }
+            },
+            quote! {
+                #[derive(Debug, serde::Serialize)]
+                #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, MyTrait)]
+                struct S(u8);
+            },
+        );
+
+        let generated = generate(
+            &parse2(quote! {
                 #[repr(i8)]
-                pub struct S { -3..2 }
+                #[bounded_integer(skip(Debug, Neg, fmt))]
+                enum S { 0..5 }
+            })
+            .unwrap(),
+        );
+        assert!(!generated.contains("Debug"));
+        assert!(!generated.contains("Neg"));
+        assert!(!generated.contains("Display"));
+        assert!(generated.contains("impl :: core :: ops :: Add"));
+
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(skip(Clone))] struct S { 0..5 }),
+            "`Clone` is required by bounded integers and cannot be skipped",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(skip(Default))] struct S { 0..5 }),
+            "cannot skip `Default`; expected one of `Debug`, `Hash`, `ops`, `Add`, `Sub`, `Mul`, \
+            `Div`, `Rem`, `Neg`, `fmt`, `Binary`, `Display`, `LowerExp`, `LowerHex`, `Octal`, \
+            `UpperExp`, `UpperHex`",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(derive(Hash), skip(Hash))] struct S { 0..5 }),
+            "`Hash` is both derived and skipped",
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let has_default = |input| generate(&parse2(input).unwrap()).contains("Default");
+        assert!(has_default(
+            quote!(#[repr(u8)] #[bounded_integer(default)] struct S { 0..5 })
+        ));
+        assert!(has_default(
+            quote!(#[repr(i8)] #[bounded_integer(default)] enum S { -5..=0 })
+        ));
+        assert!(has_default(
+            quote!(#[repr(u8)] #[bounded_integer(default)] struct S { N.. })
+        ));
+        assert!(has_default(
+            quote!(#[repr(u8)] #[bounded_integer(default = 3)] struct S { 1..5 })
+        ));
+        assert!(has_default(
+            quote!(#[repr(u8)] #[bounded_integer(default = MAX)] enum S { 1..5 })
+        ));
+        assert!(!has_default(
+            quote!(#[repr(u8)] #[bounded_integer(default)] struct S { 1..5 })
+        ));
+        assert!(!has_default(
+            quote!(#[repr(i8)] #[bounded_integer(default)] enum S { -5..0 })
+        ));
+        assert!(!has_default(quote!(#[repr(u8)] struct S { 0..5 })));
+        let derived = BoundedInteger::from_derive(
+            parse2(quote! {
+                #[repr(u8)]
+                #[derive(Default)]
+                enum S { A, #[default] B }
+            })
+            .unwrap(),
+        );
+        assert!(derived.is_ok());
+
+        assert_error(
+            quote!(#[repr(u8)] #[derive(Default)] struct S { 0..5 }),
+            "deriving `Default` could create an out of range value; use \
+            `#[bounded_integer(default = ...)]` instead",
+        );
+        assert_error(
+            quote! {
+                #[repr(u8)]
+                #[bounded_integer(derive(core::default::Default))]
+                struct S { 0..5 }
             },
+            "deriving `Default` could create an out of range value; use \
+            `#[bounded_integer(default = ...)]` instead",
+        );
+    }
+
+    #[test]
+    fn test_consts() {
+        let generated = generate(
+            &parse2(quote! {
+                #[repr(u8)]
+                #[bounded_integer(consts(/** Off. */ MUTE = 0, LOUD = 2 * 45))]
+                enum S { 0..=100 }
+            })
+            .unwrap(),
+        );
+        assert!(generated.contains("const MUTE : Self"));
+        assert!(generated.contains("const LOUD : Self"));
+        assert!(generated.contains("Off."));
+
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(consts(MAX = 1))] struct S { 0..5 }),
+            "bounded integers already have a constant `MAX`",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(consts(A = 1, A = 2))] struct S { 0..5 }),
+            "the constant `A` is given more than once",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(consts(A))] struct S { 0..5 }),
+            "expected `=`",
+        );
+    }
+
+    #[test]
+    fn test_multiple_items() {
+        let BoundedIntegers(items) = parse2(quote! {
+            #![repr(u8)]
+            #![bounded_integer(crate = path, overflow = saturate)]
+            struct A { 0..5 }
+            #[repr(i16)]
+            #[bounded_integer(crate = other)]
+            enum B { -1..=1 }
+        })
+        .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].repr().to_token_stream().to_string(), "u8");
+        assert_eq!(items[1].repr().to_token_stream().to_string(), "i16");
+        assert_eq!(
+            items[0].crate_location().to_token_stream().to_string(),
+            "path"
+        );
+        assert_eq!(
+            items[1].crate_location().to_token_stream().to_string(),
+            "other"
+        );
+        assert!(items
+            .iter()
+            .all(|item| item.options().overflow == Overflow::Saturate));
+
+        let empty = parse2::<BoundedIntegers>(quote!(#![repr(u8)]));
+        assert_eq!(
+            error_message(empty),
+            "unexpected end of input, expected `enum`"
+        );
+        let late = parse2::<BoundedIntegers>(quote!(struct A { 0..5 } #![repr(u8)]));
+        assert_eq!(error_message(late), "expected square brackets");
+    }
+
+    #[test]
+    fn test_value_sets() {
+        let bounded_integer = parse2::<BoundedInteger>(quote! {
+            #[repr(i16)]
+            enum S { 5 | -10..-5 step 2 | 10..=(1 | 2) * 4 }
+        })
+        .unwrap();
+        let variants = bounded_integer.variants().iter().map(Ident::to_string);
+        let expected = ["N10", "N8", "N6", "P5", "P10", "P11", "P12"];
+        assert_eq!(variants.collect::<Vec<_>>(), expected);
+        assert!(bounded_integer.gaps().is_some());
+
+        let contiguous = parse2::<BoundedInteger>(quote!(#[repr(u8)] struct S { 0..5 | 5 }));
+        assert!(contiguous.unwrap().gaps().is_none());
+        let auto = parse2::<BoundedInteger>(quote!(enum S { 0 | 255..=256 step 7 })).unwrap();
+        assert!(auto.repr().is_ident("u16"));
+        let attribute = BoundedInteger::from_attribute(
+            quote!(0..10 step 3, modular),
+            parse2(quote!(
+                struct S(u8);
+            ))
+            .unwrap(),
+        );
+        assert_eq!(
+            error_message(attribute),
+            "modular bounded integers must have a single range of values",
+        );
+
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..5 | 3 }),
+            "the values of a bounded integer must not overlap",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0 step 2 }),
+            "only ranges can have a step",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..5 step 0 }),
+            "the step must be positive",
+        );
+        assert_error(
+            quote!(#[repr(i8)] struct S { 0..5 step -1 }),
+            "the step must be positive",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0.. step 2 }),
+            "the bounds of enum ranges and of ranges with gaps or a step must be closed",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..5 | N }),
+            "expected `MIN`, `MAX` or `BITS` of a primitive integer type",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..5 | 256 }),
+            "literal out of range for `u8`",
+        );
+        assert_error(
+            quote!(#[repr(T)] #[bounded_integer(signed = false)] struct S { 0 | 2 }),
+            "the repr of a bounded integer with gaps or a step must be a primitive integer type",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(modular)] enum S { 0 | 2 }),
+            "modular bounded integers must have a single range of values",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(rename(1 = One))] enum S { 0 | 2 }),
+            "renamed value is out of range",
+        );
+        assert_error(
+            quote!(#[repr(u8)] struct S { 0..5 | }),
+            "unexpected end of input, expected expression",
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let generated = generate(
+            &parse2(quote! {
+                #[repr(u8)]
+                #[bounded_integer(check = is_odd)]
+                struct S { 1..=9 }
+            })
+            .unwrap(),
+        );
+        assert!(generated.contains("is_odd (n)"));
+        assert!(generated.contains("is_odd (S :: MIN_VALUE)"));
+        assert!(!generated.contains("fn new_saturating"));
+        assert!(!generated.contains("fn saturating_add"));
+        assert!(!generated.contains("BoundedInteger for S"));
+        assert!(generated.contains("It has no `new_saturating`"));
+
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(check = is_odd)] enum S { 1..=9 }),
+            "the `check` option can only be used with structs, as every variant of an enum is a \
+            value",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(check = is_odd, modular)] struct S { 0..9 }),
+            "modular bounded integers cannot have a check, as their arithmetic has to be able to \
+            produce every value in range",
+        );
+        assert_error(
             quote! {
-                #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-                pub struct S(i8);
+                #[repr(u8)]
+                #[bounded_integer(check = is_odd, overflow = saturate)]
+                struct S { 1..=9 }
             },
+            "bounded integers with a check cannot saturate, as a saturated value might not pass \
+            the check",
+        );
+    }
+
+    #[test]
+    fn test_char() {
+        let generated = generate(
+            &parse2(quote! {
+                struct S { 'a'..'{' }
+            })
+            .unwrap(),
+        );
+        assert!(generated.contains("struct S (char)"));
+        assert!(generated.contains("MAX_VALUE : char = 'z'"));
+        assert!(generated.contains("fn succ"));
+        assert!(generated.contains("FromStr for S"));
+        assert!(!generated.contains("fn checked_add"));
+        assert!(!generated.contains("Add <"));
+        assert!(!generated.contains("LowerHex"));
+
+        assert_error(
+            quote!(#[repr(char)] enum S { 'a'..='z' }),
+            "bounded chars must be structs",
+        );
+        assert_error(
+            quote!(#[repr(char)] struct S { 'a' | 'c'..='z' }),
+            "bounded chars must have a single range of values",
+        );
+        assert_error(
+            quote!(#[repr(char)] #[bounded_integer(modular)] struct S { 'a'..='z' }),
+            "bounded chars cannot be modular",
+        );
+        assert_error(
+            quote!(#[repr(char)] #[bounded_integer(default)] struct S { 'a'..='z' }),
+            "bounded chars have no zero to default to; use `default = MIN`, `default = MAX` or \
+            `default = value` instead",
+        );
+        assert_error(
+            quote!(#[repr(char)] #[bounded_integer(signed = false)] struct S { 'a'..='z' }),
+            "the `signed` option cannot be used with `char`",
+        );
+        assert_error(
+            quote!(#[repr(char)] struct S { 'a'..END }),
+            "the end of a half-open range of chars must be a character literal",
+        );
+        assert_error(
+            quote!(#[repr(char)] struct S { ..'\0' }),
+            "the range of a bounded integer must not be empty",
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        let generated = generate(
+            &parse2(quote! {
+                #[bounded_integer(overflow = saturate)]
+                struct S { -1.0..=1.0 }
+            })
+            .unwrap(),
+        );
+        assert!(generated.contains("struct S (f64)"));
+        assert!(generated.contains("fn checked_add"));
+        assert!(!generated.contains("fn checked_pow"));
+        assert!(generated.contains("Ord for S"));
+        assert!(!generated.contains("BoundedInteger for S"));
+        assert!(!generated.contains("LowerHex"));
+
+        assert_error(
+            quote!(#[repr(f64)] enum S { 0.0..=1.0 }),
+            "bounded floats must be structs",
+        );
+        assert_error(
+            quote!(#[repr(f64)] struct S { 0.0..1.0 }),
+            "the end of a range of floats must be inclusive",
+        );
+        assert_error(
+            quote!(#[repr(f64)] struct S { 0.0 | 1.0 }),
+            "bounded floats must have a single range of values",
+        );
+        assert_error(
+            quote!(#[repr(f32)] #[bounded_integer(modular)] struct S { 0.0..=1.0 }),
+            "bounded floats cannot be modular",
+        );
+        assert_error(
+            quote!(#[repr(f32)] #[bounded_integer(signed = true)] struct S { 0.0..=1.0 }),
+            "the `signed` option cannot be used with `f32`",
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let generated = generate(
+            &parse2(quote! {
+                #[bounded_integer(scale = 100)]
+                struct S { -10_000..=10_000 }
+            })
+            .unwrap(),
+        );
+        assert!(generated.contains("const SCALE : i16 = 100"));
+        assert!(generated.contains("fn checked_mul_scaled"));
+        assert!(generated.contains("fn checked_add_bounded"));
+        assert!(generated.contains("fn checked_rem_bounded"));
+        assert!(!generated.contains("fn checked_mul_bounded"));
+        assert!(!generated.contains("fn saturating_mul_bounded"));
+        assert!(!generated.contains("fn checked_div_bounded"));
+        assert!(!generated.contains("fn checked_div_euclid_bounded"));
+        assert!(generated.contains("FromStr for S"));
+        assert_eq!(generated.matches("Display for S").count(), 1);
+
+        assert_error(
+            quote!(#[bounded_integer(scale = 0)] struct S { 0..=100 }),
+            "the scale must be a power of 10",
+        );
+        assert_error(
+            quote!(#[bounded_integer(scale = 50)] struct S { 0..=100 }),
+            "the scale must be a power of 10",
+        );
+        assert_error(
+            quote!(#[repr(u8)] #[bounded_integer(scale = 1000)] struct S { 0..=100 }),
+            "the scale does not fit in the repr `u8`",
+        );
+        assert_error(
+            quote!(#[bounded_integer(scale = 10, modular)] struct S { 0..=100 }),
+            "modular bounded integers cannot be scaled",
+        );
+        assert_error(
+            quote!(#[bounded_integer(consts(SCALE = 5), scale = 100)] struct S { 0..=100 }),
+            "fixed-point bounded integers already have a constant `SCALE`",
+        );
+        let generated = generate(
+            &parse2(quote!(#[bounded_integer(consts(SCALE = 5))] struct S { 0..=100 })).unwrap(),
+        );
+        assert!(generated.contains("const SCALE"));
+        assert_error(
+            quote!(#[repr(char)] #[bounded_integer(scale = 10)] struct S { 'a'..='z' }),
+            "bounded chars cannot be scaled",
+        );
+        assert_error(
+            quote!(#[repr(f64)] #[bounded_integer(scale = 10)] struct S { 0.0..=1.0 }),
+            "bounded floats cannot be scaled",
         );
     }
 }
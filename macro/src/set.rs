@@ -0,0 +1,203 @@
+//! Sets of values of bounded integers that don't form a single range.
+//!
+//! A set is stored as a sorted list of disjoint parts, each of which is a range of values with a
+//! step between them. Single values are parts with the same first and last value.
+
+use proc_macro2::Span;
+use syn::Error;
+
+use crate::eval::Int;
+
+/// Values from `start` to `last` inclusive, spaced `step` apart.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Part {
+    start: Int,
+    last: Int,
+    step: u128,
+}
+
+impl Part {
+    /// Creates a part from its bounds and step, which must be positive. `end` is rounded down to
+    /// the last value reachable from `start`.
+    pub(crate) fn new(start: Int, end: Int, step: u128) -> Self {
+        let offset = end.offset_from(start) / step * step;
+        // Parts with a single value are given a step of 1 so that they can be merged.
+        let step = if offset == 0 { 1 } else { step };
+        Self {
+            start,
+            last: start.add_offset(offset),
+            step,
+        }
+    }
+
+    /// Gets the number of values in the part minus one, which can't overflow.
+    fn last_index(&self) -> u128 {
+        self.last.offset_from(self.start) / self.step
+    }
+
+    fn contains(&self, value: Int) -> bool {
+        self.start <= value && value <= self.last && value.offset_from(self.start) % self.step == 0
+    }
+}
+
+/// The values of a bounded integer, which are never empty.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ValueSet {
+    parts: Vec<Part>,
+}
+
+impl ValueSet {
+    /// Creates a set of all the values in a range.
+    pub(crate) fn from_range(start: Int, end: Int) -> Self {
+        Self {
+            parts: vec![Part::new(start, end, 1)],
+        }
+    }
+
+    /// Creates a set from parts, each given with the span used to report that it overlaps with
+    /// another part. Adjacent parts with a step of 1 are merged.
+    pub(crate) fn new(mut parts: Vec<(Part, Span)>) -> syn::Result<Self> {
+        parts.sort_by_key(|(part, _)| part.start);
+
+        let mut merged: Vec<Part> = Vec::with_capacity(parts.len());
+        for (part, span) in parts {
+            if let Some(previous) = merged.last_mut() {
+                if part.start <= previous.last {
+                    return Err(Error::new(
+                        span,
+                        "the values of a bounded integer must not overlap",
+                    ));
+                }
+                if previous.step == 1
+                    && part.step == 1
+                    && part.start.offset_from(previous.last) == 1
+                {
+                    previous.last = part.last;
+                    continue;
+                }
+            }
+            merged.push(part);
+        }
+        Ok(Self { parts: merged })
+    }
+
+    pub(crate) fn min(&self) -> Int {
+        self.parts[0].start
+    }
+
+    pub(crate) fn max(&self) -> Int {
+        self.parts[self.parts.len() - 1].last
+    }
+
+    /// Checks whether the set holds every value from its minimum to its maximum.
+    pub(crate) fn is_range(&self) -> bool {
+        self.parts.len() == 1 && self.parts[0].step == 1
+    }
+
+    /// Gets the number of values in the set minus one, which can't overflow.
+    pub(crate) fn last_index(&self) -> u128 {
+        self.parts
+            .iter()
+            .map(|part| part.last_index().wrapping_add(1))
+            .fold(0_u128, u128::wrapping_add)
+            .wrapping_sub(1)
+    }
+
+    pub(crate) fn contains_zero(&self) -> bool {
+        self.parts.iter().any(|part| {
+            (part.start.is_negative() || part.start.is_zero())
+                && !part.last.is_negative()
+                && part.start.unsigned_abs() % part.step == 0
+        })
+    }
+
+    /// Gets the position of a value in the set, counting from the smallest value.
+    pub(crate) fn index_of(&self, value: Int) -> Option<u128> {
+        let mut index = 0_u128;
+        for part in &self.parts {
+            if part.contains(value) {
+                return Some(index + value.offset_from(part.start) / part.step);
+            }
+            index = index.wrapping_add(part.last_index()).wrapping_add(1);
+        }
+        None
+    }
+
+    /// Iterates over the values of the set in increasing order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = Int> + '_ {
+        self.parts.iter().flat_map(|part| {
+            (0..=part.last_index()).map(move |index| part.start.add_offset(index * part.step))
+        })
+    }
+
+    /// Gets the parts of the set as `(start, last, step)`, with the values given as offsets from
+    /// the minimum of the set.
+    pub(crate) fn offsets(&self) -> impl Iterator<Item = (u128, u128, u128)> + '_ {
+        let min = self.min();
+        self.parts.iter().map(move |part| {
+            (
+                part.start.offset_from(min),
+                part.last.offset_from(min),
+                part.step,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(parts: &[(i128, i128, u128)]) -> syn::Result<ValueSet> {
+        let parts = parts
+            .iter()
+            .map(|&(start, end, step)| {
+                let part = Part::new(Int::Signed(start), Int::Signed(end), step);
+                (part, Span::call_site())
+            })
+            .collect();
+        ValueSet::new(parts)
+    }
+
+    #[test]
+    fn test_parts() {
+        let evens = set(&[(0, 101, 2)]).unwrap();
+        assert_eq!(evens.max(), Int::Signed(100));
+        assert_eq!(evens.last_index(), 50);
+        assert_eq!(evens.index_of(Int::Signed(64)), Some(32));
+        assert_eq!(evens.index_of(Int::Signed(63)), None);
+        assert_eq!(evens.index_of(Int::Signed(102)), None);
+
+        let mixed = set(&[(10, 20, 5), (-3, -1, 1), (0, 0, 1)]).unwrap();
+        assert!(!mixed.is_range());
+        assert!(mixed.contains_zero());
+        let values = mixed.values().collect::<Vec<_>>();
+        let expected = [-3, -2, -1, 0, 10, 15, 20].iter().map(|&v| Int::Signed(v));
+        assert_eq!(values, expected.collect::<Vec<_>>());
+        let offsets = mixed.offsets().collect::<Vec<_>>();
+        assert_eq!(offsets, [(0, 3, 1), (13, 23, 5)]);
+        assert!(!set(&[(-9, 9, 2)]).unwrap().contains_zero());
+    }
+
+    #[test]
+    fn test_merging() {
+        assert!(set(&[(0, 4, 1), (5, 5, 1), (6, 9, 1)]).unwrap().is_range());
+        assert!(set(&[(0, 0, 1), (1, 1, 7)]).unwrap().is_range());
+        assert!(!set(&[(0, 4, 2), (5, 9, 1)]).unwrap().is_range());
+        assert!(set(&[(0, 4, 1), (4, 9, 1)]).is_err());
+        assert!(set(&[(0, 8, 2), (1, 9, 2)]).is_err());
+    }
+
+    #[test]
+    fn test_full_width() {
+        let all = ValueSet::from_range(Int::Unsigned(0), Int::Unsigned(u128::MAX));
+        assert_eq!(all.last_index(), u128::MAX);
+        let odd = ValueSet::new(vec![(
+            Part::new(Int::Unsigned(1), Int::Unsigned(u128::MAX), 2),
+            Span::call_site(),
+        )])
+        .unwrap();
+        assert_eq!(odd.last_index(), u128::MAX / 2);
+        assert_eq!(odd.index_of(Int::Unsigned(u128::MAX)), Some(u128::MAX / 2));
+    }
+}
@@ -0,0 +1,663 @@
+//! Implementation details of the generated bounded integers. This module is not part of the public
+//! API.
+//!
+//! The modular arithmetic functions operate on residues modulo `max + 1`, all widened to `u128`.
+//! They never overflow, even when `max + 1` itself doesn't fit in a `u128`.
+
+use crate::errors::{ParseCharError, ParseCharErrorKind, TryFromCharError};
+use crate::errors::{ParseDecimalError, ParseDecimalErrorKind};
+use crate::Rounding;
+
+use core::convert::TryFrom;
+use core::fmt::{self, Formatter};
+
+/// Computes `(a + b) mod (max + 1)`.
+#[must_use]
+pub fn mod_add(a: u128, b: u128, max: u128) -> u128 {
+    if a > max - b {
+        a - (max - b) - 1
+    } else {
+        a + b
+    }
+}
+
+/// Computes `-a mod (max + 1)`.
+#[must_use]
+pub fn mod_neg(a: u128, max: u128) -> u128 {
+    if a == 0 {
+        0
+    } else {
+        max - (a - 1)
+    }
+}
+
+/// Computes `(a - b) mod (max + 1)`.
+#[must_use]
+pub fn mod_sub(a: u128, b: u128, max: u128) -> u128 {
+    mod_add(a, mod_neg(b, max), max)
+}
+
+/// Computes `(a * b) mod (max + 1)`.
+#[must_use]
+pub fn mod_mul(a: u128, b: u128, max: u128) -> u128 {
+    let modulus = match max.checked_add(1) {
+        Some(modulus) => modulus,
+        None => return a.wrapping_mul(b),
+    };
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Fall back to doubling and adding, which stays within the modulus.
+    let (mut a, mut b, mut result) = (a, b, 0);
+    while b != 0 {
+        if b & 1 == 1 {
+            result = mod_add(result, a, max);
+        }
+        a = mod_add(a, a, max);
+        b >>= 1;
+    }
+    result
+}
+
+/// Computes `base.pow(exp) mod (max + 1)`, using exponentiation by squaring.
+#[must_use]
+pub fn mod_pow(base: u128, exp: u32, max: u128) -> u128 {
+    let (mut base, mut exp) = (base, exp);
+    let mut result = if max == 0 { 0 } else { 1 };
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, max);
+        }
+        base = mod_mul(base, base, max);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Computes the multiplicative inverse of `a` modulo `max + 1`, if it exists.
+#[must_use]
+pub fn mod_inverse(a: u128, max: u128) -> Option<u128> {
+    match (max, a) {
+        // In the zero ring 0 is both the additive and the multiplicative identity.
+        (0, _) => return Some(0),
+        (_, 0) => return None,
+        (_, 1) => return Some(1),
+        _ => {}
+    }
+
+    // The extended Euclidean algorithm on `max + 1` and `a`, tracking only the coefficients of
+    // `a`. The first step is done separately as `max + 1` may not be representable.
+    let (quotient, remainder) = (max / a, max % a);
+    let (quotient, remainder) = if remainder + 1 == a {
+        (quotient + 1, 0)
+    } else {
+        (quotient, remainder + 1)
+    };
+
+    let (mut old_r, mut r) = (a, remainder);
+    let (mut old_t, mut t) = (1, mod_neg(quotient, max));
+    while r != 0 {
+        let quotient = old_r / r;
+        let next_r = old_r - quotient * r;
+        let next_t = mod_sub(old_t, mod_mul(quotient, t, max), max);
+        old_r = r;
+        r = next_r;
+        old_t = t;
+        t = next_t;
+    }
+
+    if old_r == 1 {
+        Some(old_t)
+    } else {
+        None
+    }
+}
+
+/// Wraps `n` into the `range` values starting at `min`, with all values given as two's complement
+/// `u128`s. `below` is whether `n` is less than `min`, and a `range` of 0 means all 2^128 values,
+/// in which case `n` can't be below `min`.
+#[must_use]
+pub fn wrap(n: u128, min: u128, range: u128, below: bool) -> u128 {
+    // The wrapping differences are exact as the distance between `n` and `min` is always less than
+    // 2^128.
+    let offset = if below {
+        match min.wrapping_sub(n) % range {
+            0 => 0,
+            distance => range - distance,
+        }
+    } else {
+        let distance = n.wrapping_sub(min);
+        distance.checked_rem(range).unwrap_or(distance)
+    };
+    min.wrapping_add(offset)
+}
+
+/// Checks whether `offset` is one of the values of a set, given as parts of `(start, last, step)`
+/// sorted by value. All values are offsets from the minimum of the set.
+#[must_use]
+pub const fn set_contains(offset: u128, parts: &[(u128, u128, u128)]) -> bool {
+    let mut i = 0;
+    while i < parts.len() {
+        let (start, last, step) = parts[i];
+        if offset <= last {
+            return offset >= start && (offset - start) % step == 0;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Finds the value of a set that is nearest to `offset`, which must not be greater than the last
+/// value of the set, preferring the smaller value on ties. The set is given like in
+/// [`set_contains`].
+#[must_use]
+pub fn set_nearest(offset: u128, parts: &[(u128, u128, u128)]) -> u128 {
+    let mut below = 0;
+    for &(start, last, step) in parts {
+        if offset < start {
+            return if start - offset < offset - below {
+                start
+            } else {
+                below
+            };
+        }
+        if offset <= last {
+            let down = offset - (offset - start) % step;
+            // This doesn't overflow as `last` is at least `down + step` if `down` isn't `offset`.
+            let up = down + step;
+            return if down == offset || offset - down <= up - offset {
+                down
+            } else {
+                up
+            };
+        }
+        below = last;
+    }
+    below
+}
+
+/// Iterates over the values of a set in increasing order, as offsets. The set is given like in
+/// [`set_contains`].
+pub fn set_values(parts: &'static [(u128, u128, u128)]) -> impl DoubleEndedIterator<Item = u128> {
+    parts
+        .iter()
+        .flat_map(|&(start, last, step)| (0..=(last - start) / step).map(move |i| start + i * step))
+}
+
+/// The first surrogate, which along with the 0x800 values after it isn't a char.
+const SURROGATES_START: u32 = 0xD800;
+const SURROGATES_LEN: u32 = 0x800;
+
+/// Gets the position of a char among all chars, which skips the surrogates.
+#[must_use]
+pub const fn char_index(c: char) -> u32 {
+    let n = c as u32;
+    if n < SURROGATES_START {
+        n
+    } else {
+        n - SURROGATES_LEN
+    }
+}
+
+/// Gets the char at a position among all chars, the inverse of [`char_index`].
+#[must_use]
+pub fn char_from_index(index: u32) -> char {
+    let n = if index < SURROGATES_START {
+        index
+    } else {
+        index + SURROGATES_LEN
+    };
+    char::from_u32(n).expect("char index out of range")
+}
+
+/// Counts the chars from `min` to `max` inclusive. The subtraction wraps so that an empty range
+/// is only reported by the assertion that checks for it.
+#[must_use]
+pub const fn char_count(min: char, max: char) -> u128 {
+    char_index(max).wrapping_sub(char_index(min)) as u128 + 1
+}
+
+macro_rules! float_index {
+    ($($name:ident: $ty:ty,)*) => {$(
+        /// Gets the position of a float that isn't NaN among the floats ordered by value, with
+        /// both zeros at 0.
+        #[must_use]
+        pub const fn $name(x: $ty) -> i128 {
+            if x < 0.0 {
+                -((-x).to_bits() as i128)
+            } else if x == 0.0 {
+                0
+            } else {
+                x.to_bits() as i128
+            }
+        }
+    )*};
+}
+float_index! {
+    f32_index: f32,
+    f64_index: f64,
+}
+
+/// Counts the floats from the float at index `min` to the float at index `max` inclusive.
+#[must_use]
+pub const fn float_count(min: i128, max: i128) -> u128 {
+    max.wrapping_sub(min) as u128 + 1
+}
+
+/// Converts a char to a bounded char with its `new` function.
+pub fn try_from_char<T>(c: char, new: fn(char) -> Option<T>) -> Result<T, TryFromCharError> {
+    new(c).ok_or(TryFromCharError(()))
+}
+
+/// Parses a bounded char from a string holding exactly one char, with its `new` function.
+pub fn parse_char<T>(s: &str, new: fn(char) -> Option<T>) -> Result<T, ParseCharError> {
+    let mut chars = s.chars();
+    let c = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        (None, _) => return Err(ParseCharError(ParseCharErrorKind::Empty)),
+        (Some(_), Some(_)) => return Err(ParseCharError(ParseCharErrorKind::TooManyChars)),
+    };
+    new(c).ok_or(ParseCharError(ParseCharErrorKind::OutOfRange))
+}
+
+/// Formats the magnitude of a fixed-point bounded integer as a decimal with `digits` digits after
+/// the point, respecting the width, fill and sign flags of the formatter.
+pub fn fmt_scaled(
+    f: &mut Formatter<'_>,
+    negative: bool,
+    magnitude: u128,
+    digits: u32,
+) -> fmt::Result {
+    // A `u128` has at most 39 digits, and there are at most 38 digits after the point, which can
+    // all be zeros in front of the digits of the magnitude.
+    let mut buf = [0; 80];
+    let mut start = buf.len();
+    let mut push = |byte| {
+        start -= 1;
+        buf[start] = byte;
+    };
+    let mut rest = magnitude;
+    for _ in 0..digits {
+        push(b'0' + (rest % 10) as u8);
+        rest /= 10;
+    }
+    if digits > 0 {
+        push(b'.');
+    }
+    loop {
+        push(b'0' + (rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    let s = core::str::from_utf8(&buf[start..]).unwrap();
+    f.pad_integral(!negative, "", s)
+}
+
+/// Parses a fixed-point bounded integer from a decimal with at most `digits` digits after the
+/// point, with its `new` function.
+pub fn parse_scaled<T, B>(
+    s: &str,
+    digits: u32,
+    new: fn(T) -> Option<B>,
+) -> Result<B, ParseDecimalError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    let error = |kind| Err(ParseDecimalError(kind));
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if whole.is_empty() && fraction.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return error(ParseDecimalErrorKind::Invalid);
+    }
+    if fraction.len() > digits as usize {
+        return error(ParseDecimalErrorKind::TooPrecise);
+    }
+
+    // Missing digits after the point are zeros, which are added by scaling up at the end.
+    let padding = 10_u128.pow(digits - fraction.len() as u32);
+    let mut magnitude = 0_u128;
+    for digit in whole.bytes().chain(fraction.bytes()) {
+        magnitude = match magnitude
+            .checked_mul(10)
+            .and_then(|magnitude| magnitude.checked_add(u128::from(digit - b'0')))
+        {
+            Some(magnitude) => magnitude,
+            None => return error(ParseDecimalErrorKind::OutOfRange),
+        };
+    }
+    let magnitude = match magnitude.checked_mul(padding) {
+        Some(magnitude) => magnitude,
+        None => return error(ParseDecimalErrorKind::OutOfRange),
+    };
+
+    let value = if negative {
+        i128::try_from(magnitude)
+            .ok()
+            .and_then(|magnitude| T::try_from(-magnitude).ok())
+    } else {
+        T::try_from(magnitude).ok()
+    };
+    match value.and_then(new) {
+        Some(value) => Ok(value),
+        None => error(ParseDecimalErrorKind::OutOfRange),
+    }
+}
+
+/// Multiplies a float by the scale of a fixed-point bounded integer and rounds it to an integer,
+/// returning `None` if it is NaN or doesn't fit in an `i128`.
+#[must_use]
+pub fn scale_f64(x: f64, scale: f64, rounding: Rounding) -> Option<i128> {
+    // 2^127, the first value that doesn't fit in an `i128`. The comparisons also reject NaN.
+    const LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+    let scaled = x * scale;
+    if !(-LIMIT < scaled && scaled < LIMIT) {
+        return None;
+    }
+    // Floats this large are integers, so the fraction is exact.
+    let truncated = scaled as i128;
+    let fraction = scaled - truncated as f64;
+    let adjustment = match rounding {
+        Rounding::Nearest if fraction >= 0.5 => 1,
+        Rounding::Nearest if fraction <= -0.5 => -1,
+        Rounding::Down if fraction < 0.0 => -1,
+        Rounding::Up if fraction > 0.0 => 1,
+        _ => 0,
+    };
+    truncated.checked_add(adjustment)
+}
+
+/// Computes `a * b / c` exactly for the fixed-point methods of bounded integers, rounding towards
+/// zero. If the result doesn't fit in `T`, returns whether it is negative instead. Panics if `c`
+/// is 0.
+pub fn mul_div<T>(a: T, b: T, c: T) -> Result<T, bool>
+where
+    T: Copy + TryFrom<u128> + TryFrom<i128>,
+    u128: TryFrom<T>,
+    i128: TryFrom<T>,
+{
+    // Every primitive is either a `u128` or a negative `i128`.
+    let split = |n: T| match u128::try_from(n) {
+        Ok(magnitude) => (false, magnitude),
+        Err(_) => match i128::try_from(n) {
+            Ok(n) => (true, n.unsigned_abs()),
+            Err(_) => unreachable!(),
+        },
+    };
+    let ((a_negative, a), (b_negative, b), (c_negative, c)) = (split(a), split(b), split(c));
+    let negative = a_negative ^ b_negative ^ c_negative;
+    if c == 0 {
+        panic!("attempt to divide by zero");
+    }
+
+    let (high, low) = mul_wide(a, b);
+    let magnitude = if high == 0 {
+        low / c
+    } else if high >= c {
+        return Err(negative);
+    } else {
+        div_wide(high, low, c)
+    };
+    let result = if !negative {
+        T::try_from(magnitude).ok()
+    } else if magnitude <= 1 << 127 {
+        T::try_from(0_i128.wrapping_sub(magnitude as i128)).ok()
+    } else {
+        None
+    };
+    result.ok_or(negative)
+}
+
+/// Computes the full 256-bit product of two `u128`s, as its high and low halves.
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let mask = u128::from(u64::MAX);
+    let (a_high, a_low) = (a >> 64, a & mask);
+    let (b_high, b_low) = (b >> 64, b & mask);
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let middle = (low_low >> 64) + (high_low & mask) + (low_high & mask);
+    let low = (low_low & mask) | (middle << 64);
+    let high = a_high * b_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Divides a 256-bit number, given as its high and low halves, by `divisor` with long division.
+/// The high half must be less than the divisor, so that the quotient fits in a `u128`.
+fn div_wide(high: u128, low: u128, divisor: u128) -> u128 {
+    let (mut remainder, mut quotient) = (high, 0);
+    for bit in (0..128).rev() {
+        // The remainder is less than the divisor, so doubling it overflows by at most one bit.
+        let carry = remainder >> 127 == 1;
+        remainder = (remainder << 1) | (low >> bit & 1);
+        quotient <<= 1;
+        if carry || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_width() {
+        let max = u128::MAX;
+        assert_eq!(mod_add(max, 2, max), 1);
+        assert_eq!(mod_sub(1, 2, max), max);
+        assert_eq!(mod_neg(1, max), max);
+        assert_eq!(mod_mul(max, max, max), 1);
+        assert_eq!(mod_pow(3, 5, max), 243);
+        assert_eq!(mod_inverse(2, max), None);
+        for &a in &[3, 5, max, max - 2, 0x1234_5678_9abc_def1] {
+            assert_eq!(mod_mul(a, mod_inverse(a, max).unwrap(), max), 1);
+        }
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        // 2^127 - 1, a Mersenne prime.
+        let max = u128::MAX / 2 - 1;
+        assert_eq!(mod_mul(max, max, max), 1);
+        assert_eq!(mod_mul(max - 1, 2, max), max - 3);
+        for &a in &[2, 3, max, max / 3, 0x1234_5678_9abc_def1] {
+            assert_eq!(mod_mul(a, mod_inverse(a, max).unwrap(), max), 1);
+        }
+    }
+
+    #[test]
+    fn test_wrap_exhaustive() {
+        for min in i8::MIN..=i8::MAX {
+            for max in min..=i8::MAX {
+                let range = (i16::from(max) - i16::from(min) + 1) as u128;
+                for n in i8::MIN..=i8::MAX {
+                    let expected =
+                        (i16::from(n) - i16::from(min)).rem_euclid(range as i16) + i16::from(min);
+                    let wrapped = wrap(n as u128, min as u128, range, n < min);
+                    assert_eq!(i16::from(wrapped as i8), expected);
+                }
+            }
+        }
+        for min in u8::MIN..=u8::MAX {
+            for max in min..=u8::MAX {
+                let range = u128::from(max - min) + 1;
+                for n in u8::MIN..=u8::MAX {
+                    let expected =
+                        (i16::from(n) - i16::from(min)).rem_euclid(range as i16) + i16::from(min);
+                    let wrapped = wrap(u128::from(n), u128::from(min), range, n < min);
+                    assert_eq!(i16::from(wrapped as u8), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_full_width() {
+        let (min, max) = (i128::MIN as u128, i128::MAX as u128);
+        assert_eq!(wrap(min, min, 0, false), min);
+        assert_eq!(wrap(max, min, 0, false), max);
+        assert_eq!(wrap(5, 0, 0, false), 5);
+        assert_eq!(wrap(u128::MAX, 0, u128::MAX, false), 0);
+        assert_eq!(wrap(u128::MAX, 1, u128::MAX - 1, false), 1);
+        // From -1 to `i128::MAX`.
+        assert_eq!(wrap(min, (-1_i128) as u128, (1 << 127) + 1, true), 1);
+    }
+
+    #[test]
+    fn test_small_modulus() {
+        for max in 0..20 {
+            let modulus = max + 1;
+            for a in 0..=max {
+                for b in 0..=max {
+                    assert_eq!(mod_add(a, b, max), (a + b) % modulus);
+                    assert_eq!(mod_sub(a, b, max), (a + modulus - b) % modulus);
+                    assert_eq!(mod_mul(a, b, max), a * b % modulus);
+                }
+                let inverse = (0..=max).find(|&b| a * b % modulus == 1 % modulus);
+                assert_eq!(mod_inverse(a, max), inverse);
+                assert_eq!(mod_pow(a, 3, max), a * a * a % modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sets() {
+        // 0, 1, 2, 6, 10, 14 and 20.
+        let parts = [(0, 2, 1), (6, 14, 4), (20, 20, 1)];
+        let values = [0, 1, 2, 6, 10, 14, 20];
+        for offset in 0..=20 {
+            assert_eq!(set_contains(offset, &parts), values.contains(&offset));
+            let nearest = values
+                .iter()
+                .copied()
+                .min_by_key(|&value| (value as i128 - offset as i128).abs())
+                .unwrap();
+            assert_eq!(set_nearest(offset, &parts), nearest, "{}", offset);
+        }
+        assert!(!set_contains(21, &parts));
+        assert!(set_values(&[(0, 2, 1), (6, 14, 4), (20, 20, 1)]).eq(values.iter().copied()));
+
+        let full = [(0, u128::MAX - 1, u128::MAX / 2), (u128::MAX, u128::MAX, 1)];
+        assert_eq!(set_nearest(u128::MAX - 2, &full), u128::MAX - 1);
+        assert_eq!(set_nearest(u128::MAX / 4, &full), 0);
+        let full_values =
+            set_values(&[(0, u128::MAX - 1, u128::MAX / 2), (u128::MAX, u128::MAX, 1)]);
+        let expected = [u128::MAX, u128::MAX - 1, u128::MAX / 2, 0];
+        assert!(full_values.rev().eq(expected.iter().copied()));
+    }
+
+    #[test]
+    fn test_chars() {
+        for &c in &['\0', 'a', '\u{D7FF}', '\u{E000}', char::MAX] {
+            assert_eq!(char_from_index(char_index(c)), c);
+        }
+        assert_eq!(char_index('\u{E000}'), char_index('\u{D7FF}') + 1);
+        assert_eq!(char_count('a', 'z'), 26);
+        assert_eq!(char_count('\0', char::MAX), 0x110000 - 0x800);
+    }
+
+    #[test]
+    fn test_floats() {
+        assert_eq!(f64_index(-0.0), f64_index(0.0));
+        assert_eq!(f64_index(f64::from_bits(1)), 1);
+        assert_eq!(f64_index(-f64::from_bits(1)), -1);
+        assert!(f64_index(f64::NEG_INFINITY) < f64_index(f64::MIN));
+        assert_eq!(float_count(f32_index(1.0), f32_index(2.0)), (1 << 23) + 1);
+        let one = u128::from(1.0_f32.to_bits());
+        assert_eq!(float_count(f32_index(-1.0), f32_index(1.0)), 2 * one + 1);
+    }
+
+    #[test]
+    fn test_scaled() {
+        struct Scaled(bool, u128, u32);
+        impl fmt::Display for Scaled {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                fmt_scaled(f, self.0, self.1, self.2)
+            }
+        }
+        assert_eq!(Scaled(false, 1234, 2).to_string(), "12.34");
+        assert_eq!(Scaled(true, 5, 2).to_string(), "-0.05");
+        assert_eq!(Scaled(false, 7, 0).to_string(), "7");
+        assert_eq!(format!("{:>7}", Scaled(false, 5, 3)), "  0.005");
+        assert_eq!(format!("{:+}", Scaled(false, 0, 1)), "+0.0");
+        assert_eq!(Scaled(false, u128::MAX, 38).to_string().len(), 40);
+
+        let parse = |s| parse_scaled::<i16, i16>(s, 2, Some).map_err(|e| e.0);
+        assert_eq!(parse("12.34"), Ok(1234));
+        assert_eq!(parse("-0.5"), Ok(-50));
+        assert_eq!(parse("+1"), Ok(100));
+        assert_eq!(parse(".5"), Ok(50));
+        assert_eq!(parse("5."), Ok(500));
+        assert_eq!(parse("1.234"), Err(ParseDecimalErrorKind::TooPrecise));
+        assert_eq!(parse("400"), Err(ParseDecimalErrorKind::OutOfRange));
+        for &invalid in &["", ".", "-", "1e2", "--1", " 1", "1.-2"] {
+            assert_eq!(parse(invalid), Err(ParseDecimalErrorKind::Invalid));
+        }
+        assert_eq!(parse_scaled::<u8, u8>("-0", 1, Some), Ok(0));
+        assert!(parse_scaled::<u8, u8>("-0.1", 1, Some).is_err());
+
+        let cases = [
+            (Rounding::Nearest, 13, -13),
+            (Rounding::Down, 12, -13),
+            (Rounding::Up, 13, -12),
+            (Rounding::TowardZero, 12, -12),
+        ];
+        for &(rounding, positive, negative) in &cases {
+            assert_eq!(scale_f64(0.125, 100.0, rounding), Some(positive));
+            assert_eq!(scale_f64(-0.125, 100.0, rounding), Some(negative));
+            assert_eq!(scale_f64(2.0, 100.0, rounding), Some(200));
+            assert_eq!(scale_f64(f64::NAN, 100.0, rounding), None);
+            assert_eq!(scale_f64(1e40, 1.0, rounding), None);
+        }
+    }
+
+    #[test]
+    fn test_mul_div_exhaustive() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                for &c in &[1, -1, 3, -7, 10, i8::MIN, i8::MAX] {
+                    let exact = i32::from(a) * i32::from(b) / i32::from(c);
+                    let expected = i8::try_from(exact).map_err(|_| exact < 0);
+                    assert_eq!(mul_div(a, b, c), expected, "{} * {} / {}", a, b, c);
+                }
+            }
+        }
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let exact = u32::from(a) * u32::from(b) / 10;
+                assert_eq!(mul_div(a, b, 10), u8::try_from(exact).map_err(|_| false));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_div_full_width() {
+        let max = u128::MAX;
+        assert_eq!(mul_div(max, max, max), Ok(max));
+        assert_eq!(mul_div(max, 10, 10), Ok(max));
+        assert_eq!(mul_div(max, max, max - 1), Err(false));
+        assert_eq!(mul_div(max, 3, 4), Ok(max / 4 * 3 + 2));
+        assert_eq!(mul_div(1_u128 << 127, 1 << 127, 1 << 126), Err(false));
+        assert_eq!(mul_div(1_u128 << 100, 1 << 100, 1 << 80), Ok(1 << 120));
+
+        let (min, max) = (i128::MIN, i128::MAX);
+        assert_eq!(mul_div(max, max, max), Ok(max));
+        assert_eq!(mul_div(min, min, min), Ok(min));
+        assert_eq!(mul_div(min, 10, 10), Ok(min));
+        assert_eq!(mul_div(min, -10, 10), Err(false));
+        assert_eq!(mul_div(max, -10, 10), Ok(-max));
+        assert_eq!(mul_div(max, max, -1), Err(true));
+        assert_eq!(mul_div(min, -1, -1), Ok(min));
+        assert_eq!(mul_div(-7_i128, 1, 2), Ok(-3));
+        assert_eq!(mul_div(0_i128, min, -1), Ok(0));
+    }
+}
@@ -0,0 +1,496 @@
+use core::cmp::Ordering;
+use core::fmt::{self, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal};
+use core::fmt::{UpperExp, UpperHex};
+use core::hash::{Hash, Hasher};
+
+/// A primitive integer type, which can be used as the repr of a [`DynBounded`]. This trait is
+/// sealed and implemented for all the primitive integer types.
+pub trait Primitive:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + Binary
+    + LowerExp
+    + LowerHex
+    + Octal
+    + UpperExp
+    + UpperHex
+    + sealed::Sealed
+{
+}
+
+mod sealed {
+    /// The operations of the primitive integer types used by [`DynBounded`](super::DynBounded).
+    pub trait Sealed: Sized {
+        fn checked_add(self, rhs: Self) -> Option<Self>;
+        fn checked_sub(self, rhs: Self) -> Option<Self>;
+        fn checked_mul(self, rhs: Self) -> Option<Self>;
+        fn checked_div(self, rhs: Self) -> Option<Self>;
+        fn checked_div_euclid(self, rhs: Self) -> Option<Self>;
+        fn checked_rem(self, rhs: Self) -> Option<Self>;
+        fn checked_rem_euclid(self, rhs: Self) -> Option<Self>;
+        fn checked_pow(self, exp: u32) -> Option<Self>;
+        fn checked_neg(self) -> Option<Self>;
+        fn checked_abs(self) -> Option<Self>;
+        fn saturating_add(self, rhs: Self) -> Self;
+        fn saturating_sub(self, rhs: Self) -> Self;
+        fn saturating_mul(self, rhs: Self) -> Self;
+        fn saturating_pow(self, exp: u32) -> Self;
+        fn saturating_neg(self) -> Self;
+        fn saturating_abs(self) -> Self;
+        /// Converts the value to a two's complement `u128`.
+        fn to_u128(self) -> u128;
+        /// Truncates a two's complement `u128` to the type.
+        fn from_u128(n: u128) -> Self;
+    }
+}
+
+macro_rules! impl_primitive {
+    (@common) => {
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            self.checked_add(rhs)
+        }
+        fn checked_sub(self, rhs: Self) -> Option<Self> {
+            self.checked_sub(rhs)
+        }
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            self.checked_mul(rhs)
+        }
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+            self.checked_div(rhs)
+        }
+        fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+            self.checked_div_euclid(rhs)
+        }
+        fn checked_rem(self, rhs: Self) -> Option<Self> {
+            self.checked_rem(rhs)
+        }
+        fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+            self.checked_rem_euclid(rhs)
+        }
+        fn checked_pow(self, exp: u32) -> Option<Self> {
+            self.checked_pow(exp)
+        }
+        fn checked_neg(self) -> Option<Self> {
+            self.checked_neg()
+        }
+        fn saturating_add(self, rhs: Self) -> Self {
+            self.saturating_add(rhs)
+        }
+        fn saturating_sub(self, rhs: Self) -> Self {
+            self.saturating_sub(rhs)
+        }
+        fn saturating_mul(self, rhs: Self) -> Self {
+            self.saturating_mul(rhs)
+        }
+        fn saturating_pow(self, exp: u32) -> Self {
+            self.saturating_pow(exp)
+        }
+        fn to_u128(self) -> u128 {
+            self as u128
+        }
+        fn from_u128(n: u128) -> Self {
+            n as Self
+        }
+    };
+    (unsigned: $($ty:ty),*) => {$(
+        impl Primitive for $ty {}
+        impl sealed::Sealed for $ty {
+            impl_primitive!(@common);
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+            fn saturating_neg(self) -> Self {
+                0
+            }
+            fn saturating_abs(self) -> Self {
+                self
+            }
+        }
+    )*};
+    (signed: $($ty:ty),*) => {$(
+        impl Primitive for $ty {}
+        impl sealed::Sealed for $ty {
+            impl_primitive!(@common);
+            fn checked_abs(self) -> Option<Self> {
+                self.checked_abs()
+            }
+            fn saturating_neg(self) -> Self {
+                self.saturating_neg()
+            }
+            fn saturating_abs(self) -> Self {
+                self.saturating_abs()
+            }
+        }
+    )*};
+}
+
+impl_primitive!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_primitive!(signed: i8, i16, i32, i64, i128, isize);
+
+/// The bounds of a [`DynBounded`], chosen at runtime.
+///
+/// With the `serde` feature, bounds are serialized as a `(min, max)` tuple, and they can be used
+/// as a [`DeserializeSeed`](serde_crate::de::DeserializeSeed) to deserialize a [`DynBounded`]
+/// within them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+    min: T,
+    max: T,
+}
+
+impl<T: Primitive> Bounds<T> {
+    /// Creates bounds from the smallest and largest value, returning `None` if `min` is greater
+    /// than `max`.
+    #[must_use]
+    pub fn new(min: T, max: T) -> Option<Self> {
+        if min <= max {
+            Some(Self { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest value within the bounds.
+    #[must_use]
+    pub fn min(self) -> T {
+        self.min
+    }
+
+    /// The largest value within the bounds.
+    #[must_use]
+    pub fn max(self) -> T {
+        self.max
+    }
+
+    /// Checks whether the given value is within the bounds.
+    #[must_use]
+    pub fn contains(self, n: T) -> bool {
+        self.min <= n && n <= self.max
+    }
+
+    /// The number of values within the bounds. This is 0 if they contain all 2<sup>128</sup>
+    /// values of a 128-bit integer.
+    #[must_use]
+    pub fn range(self) -> u128 {
+        self.max
+            .to_u128()
+            .wrapping_sub(self.min.to_u128())
+            .wrapping_add(1)
+    }
+}
+
+/// An integer whose bounds are chosen at runtime, such as from a configuration file.
+///
+/// This mirrors the API of the bounded integers generated by
+/// [`bounded_integer!`](crate::bounded_integer), with the bounds given as a [`Bounds`] when
+/// creating values and carried along with them. Arithmetic methods keep the bounds of `self`.
+///
+/// Values are compared and hashed by their value alone, and formatted like their value.
+///
+/// ```rust
+/// use bounded_integer::{Bounds, DynBounded};
+///
+/// let max_connections = 64;
+/// let bounds = Bounds::new(1, max_connections).unwrap();
+///
+/// let connections = DynBounded::new(60, bounds).unwrap();
+/// assert_eq!(connections.checked_add(10), None);
+/// assert_eq!(connections.saturating_add(10).get(), 64);
+/// assert_eq!(DynBounded::new(0, bounds), None);
+/// ```
+#[derive(Clone, Copy)]
+pub struct DynBounded<T> {
+    value: T,
+    bounds: Bounds<T>,
+}
+
+impl<T: Primitive> DynBounded<T> {
+    /// Creates a bounded integer if the given value is within the bounds.
+    #[must_use]
+    pub fn new(n: T, bounds: Bounds<T>) -> Option<Self> {
+        if bounds.contains(n) {
+            Some(Self { value: n, bounds })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a bounded integer by setting the value to the minimum or maximum if it is too low
+    /// or too high respectively.
+    #[must_use]
+    pub fn new_saturating(n: T, bounds: Bounds<T>) -> Self {
+        Self {
+            value: n.max(bounds.min).min(bounds.max),
+            bounds,
+        }
+    }
+
+    /// Creates a bounded integer by using modulo arithmetic. Values within the bounds won't be
+    /// changed but values outside will be wrapped around.
+    #[must_use]
+    pub fn new_wrapping(n: T, bounds: Bounds<T>) -> Self {
+        let wrapped = crate::__private::wrap(
+            n.to_u128(),
+            bounds.min.to_u128(),
+            bounds.range(),
+            n < bounds.min,
+        );
+        Self {
+            value: T::from_u128(wrapped),
+            bounds,
+        }
+    }
+
+    /// Gets the value of the bounded integer as a primitive type.
+    #[must_use]
+    pub fn get(self) -> T {
+        self.value
+    }
+
+    /// Gets the bounds of the bounded integer.
+    #[must_use]
+    pub fn bounds(self) -> Bounds<T> {
+        self.bounds
+    }
+
+    fn checked(self, n: Option<T>) -> Option<Self> {
+        n.and_then(|n| Self::new(n, self.bounds))
+    }
+
+    fn saturating(self, n: T) -> Self {
+        Self::new_saturating(n, self.bounds)
+    }
+
+    /// Checked integer addition.
+    #[must_use]
+    pub fn checked_add(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_add(rhs))
+    }
+
+    /// Saturating integer addition.
+    #[must_use]
+    pub fn saturating_add(self, rhs: T) -> Self {
+        self.saturating(self.value.saturating_add(rhs))
+    }
+
+    /// Checked integer subtraction.
+    #[must_use]
+    pub fn checked_sub(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_sub(rhs))
+    }
+
+    /// Saturating integer subtraction.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: T) -> Self {
+        self.saturating(self.value.saturating_sub(rhs))
+    }
+
+    /// Checked integer multiplication.
+    #[must_use]
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_mul(rhs))
+    }
+
+    /// Saturating integer multiplication.
+    #[must_use]
+    pub fn saturating_mul(self, rhs: T) -> Self {
+        self.saturating(self.value.saturating_mul(rhs))
+    }
+
+    /// Checked integer division.
+    #[must_use]
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_div(rhs))
+    }
+
+    /// Checked Euclidean division.
+    #[must_use]
+    pub fn checked_div_euclid(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_div_euclid(rhs))
+    }
+
+    /// Checked integer remainder.
+    #[must_use]
+    pub fn checked_rem(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_rem(rhs))
+    }
+
+    /// Checked Euclidean remainder.
+    #[must_use]
+    pub fn checked_rem_euclid(self, rhs: T) -> Option<Self> {
+        self.checked(self.value.checked_rem_euclid(rhs))
+    }
+
+    /// Checked exponentiation.
+    #[must_use]
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        self.checked(self.value.checked_pow(exp))
+    }
+
+    /// Saturating exponentiation.
+    #[must_use]
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.saturating(self.value.saturating_pow(exp))
+    }
+
+    /// Checked negation.
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.checked(self.value.checked_neg())
+    }
+
+    /// Saturating negation.
+    #[must_use]
+    pub fn saturating_neg(self) -> Self {
+        self.saturating(self.value.saturating_neg())
+    }
+
+    /// Checked absolute value.
+    #[must_use]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.checked(self.value.checked_abs())
+    }
+
+    /// Saturating absolute value.
+    #[must_use]
+    pub fn saturating_abs(self) -> Self {
+        self.saturating(self.value.saturating_abs())
+    }
+}
+
+impl<T: PartialEq> PartialEq for DynBounded<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<T: Eq> Eq for DynBounded<T> {}
+
+impl<T: PartialOrd> PartialOrd for DynBounded<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+impl<T: Ord> Ord for DynBounded<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for DynBounded<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($trait:ident),*) => {$(
+        impl<T: $trait> $trait for DynBounded<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                $trait::fmt(&self.value, f)
+            }
+        }
+    )*};
+}
+impl_fmt_traits!(Debug, Display, Binary, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{Bounds, DynBounded, Primitive};
+
+    use core::marker::PhantomData;
+
+    use serde_crate::de::{DeserializeSeed, Deserializer, Error};
+    use serde_crate::{Deserialize, Serialize, Serializer};
+
+    impl<T: Serialize> Serialize for DynBounded<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.value.serialize(serializer)
+        }
+    }
+
+    impl<T: Serialize> Serialize for Bounds<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (&self.min, &self.max).serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Primitive + Deserialize<'de>> Deserialize<'de> for Bounds<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (min, max) = <(T, T)>::deserialize(deserializer)?;
+            Self::new(min, max).ok_or_else(|| {
+                D::Error::custom(format_args!(
+                    "invalid bounds, the minimum {} is greater than the maximum {}",
+                    min, max,
+                ))
+            })
+        }
+    }
+
+    impl<'de, T: Primitive + Deserialize<'de>> DeserializeSeed<'de> for Bounds<T> {
+        type Value = DynBounded<T>;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            let value = PhantomData::<T>.deserialize(deserializer)?;
+            DynBounded::new(value, self).ok_or_else(|| {
+                D::Error::custom(format_args!(
+                    "integer out of range, expected it to be between {} and {}",
+                    self.min, self.max,
+                ))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construction() {
+        let bounds = Bounds::new(-3_i8, 5).unwrap();
+        assert_eq!(Bounds::new(1_u8, 0), None);
+        assert_eq!(bounds.range(), 9);
+        assert_eq!(DynBounded::new(6, bounds), None);
+        assert_eq!(DynBounded::new(5, bounds).unwrap().get(), 5);
+        assert_eq!(DynBounded::new_saturating(-100, bounds).get(), -3);
+        assert_eq!(DynBounded::new_saturating(100, bounds).get(), 5);
+        assert_eq!(DynBounded::new_wrapping(6, bounds).get(), -3);
+        assert_eq!(DynBounded::new_wrapping(-4, bounds).get(), 5);
+        assert_eq!(DynBounded::new_wrapping(i8::MIN, bounds).get(), -2);
+
+        let full = Bounds::new(u128::MIN, u128::MAX).unwrap();
+        assert_eq!(full.range(), 0);
+        assert_eq!(DynBounded::new_wrapping(u128::MAX, full).get(), u128::MAX);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let bounds = Bounds::new(-3_i8, 5).unwrap();
+        let two = DynBounded::new(2, bounds).unwrap();
+        assert_eq!(two.checked_add(3).unwrap().get(), 5);
+        assert_eq!(two.checked_add(4), None);
+        assert_eq!(two.saturating_mul(10).get(), 5);
+        assert_eq!(two.checked_neg().unwrap().get(), -2);
+        assert_eq!(two.saturating_sub(10).get(), -3);
+        assert_eq!(two.checked_pow(3), None);
+        assert_eq!(two.checked_div(0), None);
+        assert_eq!(two.saturating_neg().saturating_abs().get(), 2);
+        assert_eq!(two.bounds(), bounds);
+
+        let unsigned = Bounds::new(2_u8, 10).unwrap();
+        let three = DynBounded::new(3, unsigned).unwrap();
+        assert_eq!(three.saturating_neg().get(), 2);
+        assert_eq!(three.checked_abs(), Some(three));
+        assert_eq!(three.checked_rem_euclid(2), None);
+        assert_eq!(
+            format!("{:?} {} {:x}", three, three, three.saturating_mul(4)),
+            "3 3 a"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned when converting a `char` that is out of range to a bounded char with
+/// `TryFrom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromCharError(pub(crate) ());
+
+impl Display for TryFromCharError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("character out of range of the bounded char")
+    }
+}
+
+impl std::error::Error for TryFromCharError {}
+
+/// The error returned when parsing a bounded char from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCharError(pub(crate) ParseCharErrorKind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseCharErrorKind {
+    Empty,
+    TooManyChars,
+    OutOfRange,
+}
+
+impl Display for ParseCharError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.0 {
+            ParseCharErrorKind::Empty => "cannot parse a bounded char from an empty string",
+            ParseCharErrorKind::TooManyChars => "too many characters in string",
+            ParseCharErrorKind::OutOfRange => "character out of range of the bounded char",
+        })
+    }
+}
+
+impl std::error::Error for ParseCharError {}
+
+/// The error returned when parsing a fixed-point bounded integer from a decimal fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDecimalError(pub(crate) ParseDecimalErrorKind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseDecimalErrorKind {
+    Invalid,
+    TooPrecise,
+    OutOfRange,
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.0 {
+            ParseDecimalErrorKind::Invalid => "invalid decimal number",
+            ParseDecimalErrorKind::TooPrecise => "too many digits after the decimal point",
+            ParseDecimalErrorKind::OutOfRange => "number out of range of the bounded integer",
+        })
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
@@ -1,6 +1,6 @@
 //! Examples of generated bounded integers and the API they produce.
 
-use crate::bounded_integer;
+use crate::{bounded_integer, BoundedInteger};
 
 bounded_integer! {
     /// A bounded struct, implemented as a protected newtype.
@@ -28,9 +28,126 @@ bounded_integer! {
     pub enum BoundedEnum { -8..8 }
 }
 
+bounded_integer! {
+    /// A modular bounded struct, whose arithmetic operators wrap around.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(u8)]
+    /// #[bounded_integer(modular)]
+    /// pub struct ModularStruct { 0..12 }
+    /// ```
+    #[repr(u8)]
+    #[bounded_integer = crate]
+    #[bounded_integer(modular)]
+    pub struct ModularStruct { 0..12 }
+}
+
+bounded_integer! {
+    /// A modular bounded enum, whose arithmetic operators wrap around.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(i8)]
+    /// #[bounded_integer(modular)]
+    /// pub enum ModularEnum { 0..7 }
+    /// ```
+    #[repr(i8)]
+    #[bounded_integer = crate]
+    #[bounded_integer(modular)]
+    pub enum ModularEnum { 0..7 }
+}
+
+bounded_integer! {
+    /// A bounded struct whose arithmetic operators saturate instead of panicking.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(i8)]
+    /// #[bounded_integer(overflow = saturate)]
+    /// pub struct SaturatingStruct { -8..8 }
+    /// ```
+    #[repr(i8)]
+    #[bounded_integer = crate]
+    #[bounded_integer(overflow = saturate)]
+    pub struct SaturatingStruct { -8..8 }
+}
+
+bounded_integer! {
+    /// A bounded char, which has no arithmetic operators.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(char)]
+    /// pub struct BoundedChar { 'a'..='z' }
+    /// ```
+    #[repr(char)]
+    #[bounded_integer = crate]
+    pub struct BoundedChar { 'a'..='z' }
+}
+
+bounded_integer! {
+    /// A bounded float, which is never NaN.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(f64)]
+    /// pub struct BoundedFloat { 0.0..=1.0 }
+    /// ```
+    #[repr(f64)]
+    #[bounded_integer = crate]
+    pub struct BoundedFloat { 0.0..=1.0 }
+}
+
+bounded_integer! {
+    /// A fixed-point bounded integer, which is displayed and parsed as a decimal.
+    ///
+    /// This was generated from:
+    /// ```text
+    /// #[repr(u16)]
+    /// #[bounded_integer(scale = 100)]
+    /// pub struct BoundedScaled { 0..=10_000 }
+    /// ```
+    #[repr(u16)]
+    #[bounded_integer(crate = crate, scale = 100)]
+    pub struct BoundedScaled { 0..=10_000 }
+}
+
+/// A bounded enum with user-declared variants.
+///
+/// This was generated from:
+/// ```text
+/// #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+/// #[repr(u8)]
+/// pub enum Weekday {
+///     Monday = 1,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+///     Sunday,
+/// }
+/// ```
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(u8)]
+#[bounded_integer(crate = crate)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{BoundedInteger, DynBoundedInteger};
+
+    use core::convert::TryFrom;
 
     macro_rules! test_range {
         ($fn:ident, $bounded:ident) => {
@@ -47,6 +164,9 @@ mod tests {
                 assert!(!$bounded::in_range(-9));
                 assert!($bounded::in_range(7));
                 assert!(!$bounded::in_range(8));
+
+                assert_eq!(crate::bounded!($bounded, -8), $bounded::MIN);
+                assert_eq!($bounded::new_const(7), $bounded::MAX);
             }
         };
     }
@@ -102,13 +222,590 @@ mod tests {
         };
     }
 
+    macro_rules! test_ring_laws {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let values = || (0..$bounded::RANGE).map(|n| $bounded::new(n as _).unwrap());
+                let zero = $bounded::MIN;
+                let one = $bounded::new(1).unwrap();
+
+                for a in values() {
+                    assert_eq!(a + zero, a);
+                    assert_eq!(a * one, a);
+                    assert_eq!(a + -a, zero);
+                    assert_eq!(a - a, zero);
+                    assert_eq!(a * zero, zero);
+                    assert_eq!(a.pow_mod(3), a * a * a);
+                    assert_eq!(a.pow_mod(0), one);
+                    if let Some(inverse) = a.inverse() {
+                        assert_eq!(a * inverse, one);
+                    } else {
+                        assert!(values().all(|b| a * b != one));
+                    }
+
+                    for b in values() {
+                        assert_eq!(a + b, b + a);
+                        assert_eq!(a * b, b * a);
+                        assert_eq!(a - b, a + -b);
+                        assert_eq!((a - b) + b, a);
+
+                        for c in values() {
+                            assert_eq!((a + b) + c, a + (b + c));
+                            assert_eq!((a * b) * c, a * (b * c));
+                            assert_eq!(a * (b + c), a * b + a * c);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! test_bounded_rhs {
+        ($fn:ident, $bounded:ident) => {
+            #[test]
+            fn $fn() {
+                let (three, five) = ($bounded::new(3).unwrap(), $bounded::new(5).unwrap());
+                assert_eq!(three.checked_add_bounded(three).unwrap().get(), 6);
+                assert_eq!(three.checked_add_bounded(&five), None);
+                assert_eq!(three.checked_sub_bounded(5).unwrap().get(), -2);
+                assert_eq!(five.checked_rem_bounded(three).unwrap().get(), 2);
+                assert_eq!(three.checked_add_bounded(ModularEnum::MIN).unwrap(), three);
+                assert_eq!(five.saturating_mul_bounded(five), $bounded::MAX);
+                assert_eq!((-five).saturating_sub_bounded(&five), $bounded::MIN);
+                assert_eq!(i8::from(five) + i8::from(&three), 8);
+            }
+        };
+    }
+
+    fn clamp_all<T: BoundedInteger>(values: &[T::Repr]) -> impl Iterator<Item = T> + '_ {
+        values.iter().map(|&value| T::new_saturating(value))
+    }
+
+    #[test]
+    fn test_trait() {
+        assert_eq!(
+            clamp_all::<BoundedEnum>(&[-20, 0, 20]).collect::<Vec<_>>(),
+            [BoundedEnum::MIN, BoundedEnum::Z0, BoundedEnum::MAX],
+        );
+        assert_eq!(<BoundedStruct as BoundedInteger>::RANGE, 16);
+        assert_eq!(<ModularStruct as BoundedInteger>::new(12), None);
+
+        let values: [&dyn DynBoundedInteger; 3] = [
+            &BoundedStruct::new(-3).unwrap(),
+            &ModularEnum::MAX,
+            &ModularStruct::MIN,
+        ];
+        let bounds = values
+            .iter()
+            .map(|value| {
+                (
+                    value.min_value_i128(),
+                    value.get_i128(),
+                    value.max_value_i128(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bounds, [(-8, -3, 7), (0, 6, 6), (0, 0, 11)]);
+        assert!(values[0].in_range_i128(-8));
+        assert!(!values[0].in_range_i128(8));
+        assert!(!values[2].in_range_i128(-1));
+        assert!(!values[2].in_range_i128(1000));
+    }
+
     test_range!(test_struct_range, BoundedStruct);
     test_saturating!(test_struct_saturating, BoundedStruct);
     test_wrapping!(test_struct_wrapping, BoundedStruct);
     test_arithmetic!(test_struct_arithmetic, BoundedStruct);
+    test_bounded_rhs!(test_struct_bounded_rhs, BoundedStruct);
 
     test_range!(test_enum_range, BoundedEnum);
     test_saturating!(test_enum_saturating, BoundedEnum);
     test_wrapping!(test_enum_wrapping, BoundedEnum);
     test_arithmetic!(test_enum_arithmetic, BoundedEnum);
+    test_bounded_rhs!(test_enum_bounded_rhs, BoundedEnum);
+
+    test_range!(test_saturating_struct_range, SaturatingStruct);
+    test_saturating!(test_saturating_struct_saturating, SaturatingStruct);
+    test_wrapping!(test_saturating_struct_wrapping, SaturatingStruct);
+    test_arithmetic!(test_saturating_struct_arithmetic, SaturatingStruct);
+
+    #[test]
+    fn test_saturating_operators() {
+        let value = SaturatingStruct::new(5).unwrap();
+        assert_eq!(value + 5, SaturatingStruct::MAX);
+        assert_eq!(value + i8::MAX, SaturatingStruct::MAX);
+        assert_eq!(-value - 5, SaturatingStruct::MIN);
+        assert_eq!(value * -2, SaturatingStruct::MIN);
+        assert_eq!(value * value, SaturatingStruct::MAX);
+        assert_eq!(-SaturatingStruct::MIN, SaturatingStruct::MAX);
+        assert_eq!(SaturatingStruct::MIN / -1, SaturatingStruct::MAX);
+        let minus_one = SaturatingStruct::new(-1).unwrap();
+        assert_eq!((SaturatingStruct::MIN % minus_one).get(), 0);
+
+        let mut value = SaturatingStruct::new(-3).unwrap();
+        value -= 10;
+        assert_eq!(value, SaturatingStruct::MIN);
+        value *= &SaturatingStruct::MIN;
+        assert_eq!(value, SaturatingStruct::MAX);
+    }
+
+    bounded_integer! {
+        #[repr(u8)]
+        #[bounded_integer = crate]
+        struct FullU8 { 0..=255 }
+    }
+    bounded_integer! {
+        #[repr(i8)]
+        #[bounded_integer = crate]
+        struct FullI8 { .. }
+    }
+    bounded_integer! {
+        #[repr(i128)]
+        #[bounded_integer = crate]
+        struct FullI128 { .. }
+    }
+    bounded_integer! {
+        #[repr(u128)]
+        #[bounded_integer = crate]
+        struct NearlyFullU128 { 1..u128::MAX }
+    }
+
+    #[test]
+    fn test_full_width() {
+        assert_eq!(FullU8::RANGE, 256);
+        assert_eq!(FullI8::RANGE, 256);
+        assert_eq!(FullI128::RANGE, 0);
+        assert_eq!(NearlyFullU128::RANGE, u128::MAX - 1);
+
+        assert_eq!(FullU8::new_wrapping(255).get(), 255);
+        assert_eq!(FullI8::new_wrapping(-128).get(), -128);
+        assert_eq!(FullI128::new_wrapping(i128::MIN).get(), i128::MIN);
+        assert_eq!(NearlyFullU128::new_wrapping(0).get(), u128::MAX - 1);
+        assert_eq!(NearlyFullU128::new_wrapping(u128::MAX).get(), 1);
+        assert_eq!(BoundedStruct::new_wrapping(i8::MIN).get(), 0);
+        assert_eq!(BoundedStruct::new_wrapping(i8::MAX).get(), -1);
+        assert_eq!(ModularStruct::new_wrapping(u8::MAX).get(), 3);
+    }
+
+    bounded_integer! {
+        #![bounded_integer = crate]
+
+        #[repr(u8)]
+        struct Shifted { 0..1 << 4 }
+
+        #[repr(u8)]
+        struct Xored { 0..0x30 ^ 0x10 }
+    }
+
+    #[test]
+    fn test_half_open_expression() {
+        assert_eq!(Shifted::MAX_VALUE, 15);
+        assert_eq!(Shifted::new(15), Some(Shifted::MAX));
+        assert_eq!(Shifted::new(16), None);
+        assert_eq!(Xored::MAX_VALUE, 0x1f);
+        assert_eq!(Xored::new(0x20), None);
+    }
+
+    bounded_integer! {
+        #![bounded_integer = crate]
+
+        #[repr(u8)]
+        struct Even { 0..=100 step 2 }
+        #[repr(i8)]
+        enum Sparse { -3..=-1 | 5 | 10..=20 step 5 }
+        #[repr(u8)]
+        struct Gapped { 1..=5 | 10..=20 }
+    }
+
+    #[test]
+    fn test_value_sets() {
+        assert_eq!(Even::MAX_VALUE, 100);
+        assert_eq!(Even::RANGE, 51);
+        assert!(Even::in_range(42));
+        assert!(!Even::in_range(43));
+        assert_eq!(Even::new(43), None);
+        assert_eq!(Even::new_saturating(43).get(), 42);
+        assert_eq!(Even::new_saturating(255), Even::MAX);
+        assert_eq!(Even::new_wrapping(103).get(), 2);
+        assert_eq!(Even::new(40).unwrap().checked_add(1), None);
+        assert_eq!(Even::new(40).unwrap().checked_add(2).unwrap().get(), 42);
+
+        assert_eq!(Sparse::MIN, Sparse::N3);
+        assert_eq!(Sparse::MAX, Sparse::P20);
+        assert_eq!(Sparse::RANGE, 7);
+        assert_eq!(Sparse::new(15), Some(Sparse::P15));
+        assert_eq!(Sparse::P5.get(), 5);
+        assert_eq!(Sparse::new(0), None);
+        assert_eq!(Sparse::new(12), None);
+        assert_eq!(Sparse::new_saturating(1), Sparse::N1);
+        assert_eq!(Sparse::new_saturating(4), Sparse::P5);
+        assert_eq!(Sparse::new_saturating(13), Sparse::P15);
+        assert_eq!(Sparse::new_saturating(-100), Sparse::MIN);
+        assert_eq!(Sparse::P10 - 5, Sparse::P5);
+    }
+
+    #[test]
+    fn test_values() {
+        let gapped = Gapped::values().map(Gapped::get).collect::<Vec<_>>();
+        assert_eq!(gapped, (1..=5).chain(10..=20).collect::<Vec<_>>());
+        assert_eq!(Gapped::values().rev().nth(11), Gapped::new(5));
+        let even = Even::values().map(Even::get).collect::<Vec<_>>();
+        assert_eq!(even, (0..=100).step_by(2).collect::<Vec<_>>());
+        assert_eq!(Even::values().next_back(), Some(Even::MAX));
+        let sparse = Sparse::values().map(Sparse::get).collect::<Vec<_>>();
+        assert_eq!(sparse, [-3, -2, -1, 5, 10, 15, 20]);
+        assert_eq!(Sparse::values().nth(3), Some(Sparse::P5));
+
+        let odd = Odd::values().map(Odd::get).collect::<Vec<_>>();
+        assert_eq!(odd, (-9..=9).step_by(2).collect::<Vec<_>>());
+        assert_eq!(BoundedStruct::values().count(), 16);
+        assert_eq!(BoundedEnum::values().next_back(), Some(BoundedEnum::MAX));
+        assert_eq!(FullI8::values().count(), 256);
+    }
+
+    const fn is_odd(n: i8) -> bool {
+        n % 2 != 0
+    }
+
+    bounded_integer! {
+        #[repr(i8)]
+        #[bounded_integer = crate]
+        #[bounded_integer(check = is_odd)]
+        struct Odd { -9..=9 }
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(Odd::in_range(-3));
+        assert!(!Odd::in_range(4));
+        assert_eq!(Odd::new(4), None);
+        assert_eq!(Odd::MIN.checked_add(2).unwrap().get(), -7);
+        assert_eq!(Odd::MIN.checked_add(1), None);
+        assert_eq!(Odd::MAX.checked_add(2), None);
+        assert_eq!((Odd::MIN + 2).get(), -7);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_check_operator() {
+        let _ = Odd::MIN + 1;
+    }
+
+    bounded_integer! {
+        #![bounded_integer = crate]
+
+        struct Hex { '0'..'g' }
+        #[repr(core::primitive::char)]
+        #[bounded_integer(default = MAX)]
+        struct Plane { '\u{D000}'.. }
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!(BoundedChar::MIN.get(), 'a');
+        assert_eq!(BoundedChar::RANGE, 26);
+        assert_eq!(BoundedChar::new('A'), None);
+        assert_eq!(BoundedChar::new_saturating('A'), BoundedChar::MIN);
+        assert_eq!(BoundedChar::new_wrapping('{').get(), 'a');
+        assert_eq!(BoundedChar::new_wrapping('`').get(), 'z');
+        assert_eq!(BoundedChar::MIN.succ().unwrap().get(), 'b');
+        assert_eq!(BoundedChar::MAX.succ(), None);
+        assert_eq!(BoundedChar::MIN.pred(), None);
+        assert_eq!(BoundedChar::values().rev().nth(1).unwrap().get(), 'y');
+        assert_eq!(BoundedChar::values().count(), 26);
+        assert_eq!(BoundedChar::try_from('q').map(char::from), Ok('q'));
+        assert!(BoundedChar::try_from('!').is_err());
+        assert_eq!("m".parse::<BoundedChar>().unwrap().to_string(), "m");
+        assert!("".parse::<BoundedChar>().is_err());
+        assert!("mm".parse::<BoundedChar>().is_err());
+        assert!("M".parse::<BoundedChar>().is_err());
+        assert_eq!(format!("{:?}", BoundedChar::MAX), "BoundedChar('z')");
+
+        assert_eq!(Hex::MAX.get(), 'f');
+        assert_eq!(Hex::RANGE, 55);
+
+        // The surrogates are skipped.
+        assert_eq!(Plane::MAX.get(), char::MAX);
+        assert_eq!(Plane::default(), Plane::MAX);
+        assert_eq!(Plane::RANGE, 0x110000 - 0xD000 - 0x800);
+        let before = Plane::new('\u{D7FF}').unwrap();
+        assert_eq!(before.succ().unwrap().get(), '\u{E000}');
+        assert_eq!(before.succ().unwrap().pred(), Some(before));
+        assert_eq!(Plane::new_wrapping('\u{CFFF}'), Plane::MAX);
+        assert_eq!(Plane::new_saturating('\0'), Plane::MIN);
+    }
+
+    bounded_integer! {
+        #![bounded_integer = crate]
+
+        #[repr(f32)]
+        #[bounded_integer(overflow = saturate, default)]
+        struct Saturating { -1.0..=1.0 }
+        #[repr(f64)]
+        struct NonNegative { 0.0.. }
+        #[repr(f64)]
+        struct Unbounded { .. }
+    }
+
+    #[test]
+    fn test_float() {
+        use std::cmp::Ordering;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let half = BoundedFloat::new(0.5).unwrap();
+        assert_eq!(BoundedFloat::new(f64::NAN), None);
+        assert_eq!(BoundedFloat::new(1.5), None);
+        assert_eq!(BoundedFloat::new_saturating(1.5), BoundedFloat::MAX);
+        assert_eq!(BoundedFloat::new_saturating(-0.5), BoundedFloat::MIN);
+        assert_eq!(BoundedFloat::new_wrapping(1.25).get(), 0.25);
+        assert_eq!(BoundedFloat::new_wrapping(-0.25).get(), 0.75);
+        assert_eq!(BoundedFloat::new_wrapping(f64::INFINITY), BoundedFloat::MAX);
+        assert_eq!(
+            BoundedFloat::new_wrapping(f64::NEG_INFINITY),
+            BoundedFloat::MIN
+        );
+        assert_eq!(
+            BoundedFloat::MIN.saturating_mul(f64::INFINITY),
+            BoundedFloat::MIN
+        );
+        assert_eq!(half.checked_add(0.25).unwrap().get(), 0.75);
+        assert_eq!(half.checked_add(0.75), None);
+        assert_eq!(half.checked_div(0.0), None);
+        assert_eq!(half.saturating_mul(3.0), BoundedFloat::MAX);
+        assert_eq!(half.checked_neg(), None);
+        assert_eq!((half + half).get(), 1.0);
+        assert_eq!(half.max(BoundedFloat::MIN), half);
+        assert_eq!(
+            format!("{} {:?} {:e}", half, half, half),
+            "0.5 BoundedFloat(0.5) 5e-1"
+        );
+
+        let hash = |value: BoundedFloat| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let negative_zero = BoundedFloat::new(-0.0).unwrap();
+        assert_eq!(negative_zero, BoundedFloat::MIN);
+        assert_eq!(hash(negative_zero), hash(BoundedFloat::MIN));
+
+        assert_eq!(Saturating::default().get(), 0.0);
+        assert_eq!(Saturating::MAX + 1.0, Saturating::MAX);
+        assert_eq!(-Saturating::MIN, Saturating::MAX);
+        assert_eq!(Saturating::new(-0.5).unwrap().saturating_abs().get(), 0.5);
+        assert_eq!(Saturating::MAX + f32::NAN, Saturating::MIN);
+        assert_eq!(Saturating::default() * f32::INFINITY, Saturating::MIN);
+
+        assert_eq!(NonNegative::MAX_VALUE, f64::INFINITY);
+        assert_eq!(NonNegative::new(-1.0), None);
+        assert_eq!(NonNegative::new(f64::INFINITY), Some(NonNegative::MAX));
+        assert_eq!(
+            NonNegative::MAX.saturating_sub(f64::INFINITY),
+            NonNegative::MIN
+        );
+        assert_eq!(NonNegative::new_wrapping(-1.0), NonNegative::MIN);
+
+        assert_eq!(Unbounded::new(f64::NAN), None);
+        assert!(!Unbounded::in_range(f64::NAN));
+        assert_eq!(Unbounded::new(f64::NEG_INFINITY), Some(Unbounded::MIN));
+        assert_eq!(Unbounded::MAX.checked_sub(f64::INFINITY), None);
+        let one = Unbounded::new(1.0).unwrap();
+        assert_eq!(one.cmp(&Unbounded::MAX), Ordering::Less);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn test_float_saturating_nan() {
+        let _ = Saturating::new_saturating(f32::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn test_float_wrapping_nan() {
+        let _ = BoundedFloat::new_wrapping(f64::NAN);
+    }
+
+    bounded_integer! {
+        #![bounded_integer = crate]
+
+        #[repr(i32)]
+        #[bounded_integer(scale = 1000, overflow = saturate)]
+        struct Signed { -5_000..=5_000 }
+
+        #[repr(u128)]
+        #[bounded_integer(scale = 10)]
+        struct Wide { 0..=u128::MAX }
+
+        #[repr(i128)]
+        #[bounded_integer(scale = 10)]
+        struct WideSigned { i128::MIN..=i128::MAX }
+    }
+
+    #[test]
+    fn test_scaled() {
+        use crate::Rounding;
+
+        let value = BoundedScaled::new(1234).unwrap();
+        assert_eq!(BoundedScaled::SCALE, 100);
+        assert_eq!(value.to_string(), "12.34");
+        assert_eq!(
+            format!("{:>7} {:?}", value, value),
+            "  12.34 BoundedScaled(1234)"
+        );
+        assert_eq!(BoundedScaled::MIN.to_string(), "0.00");
+        assert_eq!("12.34".parse(), Ok(value));
+        assert_eq!("12.3".parse::<BoundedScaled>().unwrap().get(), 1230);
+        assert!("12.345".parse::<BoundedScaled>().is_err());
+        assert!("100.01".parse::<BoundedScaled>().is_err());
+        assert!("abc".parse::<BoundedScaled>().is_err());
+        assert!("".parse::<BoundedScaled>().is_err());
+        let error = "12.345".parse::<BoundedScaled>().unwrap_err();
+        assert_eq!(error.to_string(), "too many digits after the decimal point");
+
+        assert_eq!(value.to_f64(), 12.34);
+        let from_f64 = |x, rounding| BoundedScaled::from_f64(x, rounding).map(BoundedScaled::get);
+        assert_eq!(from_f64(0.125, Rounding::Nearest), Some(13));
+        assert_eq!(from_f64(0.125, Rounding::Down), Some(12));
+        assert_eq!(from_f64(0.125, Rounding::Up), Some(13));
+        assert_eq!(from_f64(0.125, Rounding::TowardZero), Some(12));
+        assert_eq!(from_f64(-0.001, Rounding::Nearest), Some(0));
+        assert_eq!(from_f64(-0.001, Rounding::Down), None);
+        assert_eq!(from_f64(100.01, Rounding::Nearest), None);
+        assert_eq!(from_f64(f64::NAN, Rounding::Nearest), None);
+
+        let half = BoundedScaled::new(50).unwrap();
+        let ten = BoundedScaled::new(1000).unwrap();
+        assert_eq!((ten * half).get(), 500);
+        assert_eq!((half / ten).get(), 5);
+        assert_eq!(value.checked_mul_scaled(half).unwrap().get(), 617);
+        assert_eq!(ten.checked_mul_scaled(ten).unwrap().get(), 10_000);
+        assert_eq!(ten.checked_mul_scaled(ten.saturating_add(1)), None);
+        assert_eq!(
+            ten.saturating_mul_scaled(BoundedScaled::MAX),
+            BoundedScaled::MAX
+        );
+        assert_eq!(half.checked_div_scaled(BoundedScaled::MIN), None);
+        assert_eq!(ten.checked_div_scaled(half).unwrap().get(), 2000);
+        let tiny = BoundedScaled::new(5).unwrap();
+        assert_eq!(ten.checked_div_scaled(tiny), None);
+        assert_eq!(ten.saturating_div_scaled(tiny), BoundedScaled::MAX);
+        assert_eq!(ten * 2, BoundedScaled::new(2000).unwrap());
+
+        let negative = Signed::new(-50).unwrap();
+        assert_eq!(negative.to_string(), "-0.050");
+        assert_eq!(format!("{:+}", Signed::new(5).unwrap()), "+0.005");
+        assert_eq!("-0.05".parse(), Ok(negative));
+        assert_eq!("-5".parse(), Ok(Signed::MIN));
+        assert_eq!(negative.to_f64(), -0.05);
+        assert_eq!(
+            Signed::from_f64(-0.0005, Rounding::Nearest),
+            Signed::new(-1)
+        );
+        assert_eq!(
+            Signed::from_f64(-0.0005, Rounding::TowardZero),
+            Signed::new(0)
+        );
+        let two = Signed::new(2000).unwrap();
+        assert_eq!((negative * two).get(), -100);
+        assert_eq!((negative / two).get(), -25);
+        assert_eq!(Signed::MIN * two, Signed::MIN);
+        assert_eq!(Signed::MAX / negative, Signed::MIN);
+    }
+
+    #[test]
+    fn test_scaled_full_width() {
+        let one = Wide::new(10).unwrap();
+        assert_eq!(Wide::MAX.checked_mul_scaled(one), Some(Wide::MAX));
+        assert_eq!(Wide::MAX.checked_div_scaled(one), Some(Wide::MAX));
+        assert_eq!(Wide::MAX.saturating_mul_scaled(Wide::MAX), Wide::MAX);
+        assert_eq!(Wide::MAX.checked_mul_scaled(Wide::MAX), None);
+        assert_eq!(
+            Wide::MAX.saturating_div_scaled(Wide::new(1).unwrap()),
+            Wide::MAX
+        );
+        assert_eq!(Wide::MAX.checked_div_scaled(Wide::MIN), None);
+        assert_eq!((Wide::MAX / Wide::MAX).get(), 10);
+
+        let one = WideSigned::new(10).unwrap();
+        let minus_one = WideSigned::new(-10).unwrap();
+        assert_eq!(
+            WideSigned::MIN.checked_mul_scaled(one),
+            Some(WideSigned::MIN)
+        );
+        assert_eq!(
+            WideSigned::MIN.checked_div_scaled(one),
+            Some(WideSigned::MIN)
+        );
+        assert_eq!(WideSigned::MIN.checked_mul_scaled(minus_one), None);
+        assert_eq!(
+            WideSigned::MAX.checked_div_scaled(minus_one).unwrap().get(),
+            -i128::MAX
+        );
+        assert_eq!(
+            WideSigned::MIN.saturating_mul_scaled(minus_one),
+            WideSigned::MAX
+        );
+        assert_eq!(
+            WideSigned::MIN.saturating_mul_scaled(WideSigned::MAX),
+            WideSigned::MIN
+        );
+        assert_eq!(
+            WideSigned::MAX.saturating_mul_scaled(WideSigned::MAX),
+            WideSigned::MAX
+        );
+        let tenth = WideSigned::new(-1).unwrap();
+        assert_eq!(
+            WideSigned::MAX.saturating_div_scaled(tenth),
+            WideSigned::MIN
+        );
+        assert_eq!(WideSigned::MIN / WideSigned::MIN, one);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_new_const_out_of_range() {
+        let _ = BoundedStruct::new_const(8);
+    }
+
+    #[test]
+    fn test_derived() {
+        assert_eq!(Weekday::MIN, Weekday::Monday);
+        assert_eq!(Weekday::MAX, Weekday::Sunday);
+        assert_eq!(Weekday::MIN_VALUE, 1);
+        assert_eq!(Weekday::RANGE, 7);
+        assert_eq!(Weekday::new(3), Some(Weekday::Wednesday));
+        assert_eq!(Weekday::new(0), None);
+        assert_eq!(Weekday::new(8), None);
+        assert_eq!(Weekday::new_saturating(9), Weekday::Sunday);
+        assert_eq!(Weekday::new_wrapping(8), Weekday::Monday);
+        assert_eq!(Weekday::Friday.get(), 5);
+        assert_eq!(Weekday::Friday + 2, Weekday::Sunday);
+        assert_eq!(Weekday::Friday.checked_add(3), None);
+        assert_eq!(Weekday::Friday.saturating_add(3), Weekday::Sunday);
+        assert_eq!(format!("{}", Weekday::Tuesday), "2");
+        assert_eq!(format!("{:?}", Weekday::Tuesday), "Tuesday");
+        assert_eq!(clamp_all::<Weekday>(&[0]).next(), Some(Weekday::Monday));
+    }
+
+    test_ring_laws!(test_modular_struct_ring_laws, ModularStruct);
+    test_ring_laws!(test_modular_enum_ring_laws, ModularEnum);
+
+    #[test]
+    fn test_modular() {
+        assert_eq!((ModularStruct::new(9).unwrap() + 5).get(), 2);
+        assert_eq!((ModularStruct::new(3).unwrap() - 5).get(), 10);
+        assert_eq!((ModularStruct::new(5).unwrap() * 5).get(), 1);
+        assert_eq!((-ModularStruct::new(5).unwrap()).get(), 7);
+        assert_eq!(ModularStruct::new(5).unwrap().inverse().unwrap().get(), 5);
+        assert_eq!(ModularStruct::new(4).unwrap().inverse(), None);
+        assert_eq!(ModularStruct::new(2).unwrap().pow_mod(5).get(), 8);
+
+        assert_eq!((ModularEnum::new(4).unwrap() + -5).get(), 6);
+        assert_eq!((ModularEnum::new(4).unwrap() * 5).get(), 6);
+        assert_eq!((ModularEnum::new(6).unwrap() / -2).get(), 4);
+        assert_eq!(ModularEnum::new(3).unwrap().inverse().unwrap().get(), 5);
+        assert_eq!(ModularEnum::new(3).unwrap().pow_mod(6).get(), 1);
+    }
 }
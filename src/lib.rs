@@ -4,6 +4,13 @@
 //! This crate provides the `bounded_integer` macro to generate bounded integers, as well as
 //! examples (behind the `example` feature which isn't activated by default).
 //!
+//! Every generated bounded integer implements the [`BoundedInteger`] trait, so code can be generic
+//! over them. Through it they also implement [`DynBoundedInteger`], which can be used as a trait
+//! object to inspect the bounds of any bounded integer.
+//!
+//! For bounds that are only known at runtime, such as ones read from a configuration file, there
+//! is [`DynBounded`], which carries its [`Bounds`] alongside its value.
+//!
 //! The integers generated from bounded-integer depend only on libcore and so work in `#![no_std]`
 //! environments.
 //!
@@ -11,6 +18,7 @@
 //!
 //! If you enable the `serde` feature of this crate then all bounded integers will implement
 //! `Serialize` and `Deserialize`, making sure that the internal invariants are never violated.
+//! [`Bounds`] implements `DeserializeSeed`, which deserializes a [`DynBounded`] within them.
 
 #[cfg(feature = "serde")]
 #[doc(hidden)]
@@ -19,4 +27,72 @@ pub use serde_crate as serde;
 #[cfg(feature = "examples")]
 pub mod examples;
 
-pub use bounded_integer_macro::bounded_integer;
+#[doc(hidden)]
+pub mod __private;
+
+mod traits;
+pub use traits::{BoundedInteger, DynBoundedInteger};
+
+mod dynamic;
+pub use dynamic::{Bounds, DynBounded, Primitive};
+
+mod errors;
+pub use errors::{ParseCharError, ParseDecimalError, TryFromCharError};
+
+mod scaled;
+pub use scaled::Rounding;
+
+pub use bounded_integer_macro::{bounded_integer, BoundedInteger};
+
+/// Creates a bounded integer from a constant value, checking that it is in range at compile time.
+///
+/// `bounded!(Type, value)` evaluates `Type::new_const(value)` in a constant, so an out of range
+/// value is a compile error rather than a panic and there is no runtime cost. The value can be
+/// any constant expression of the repr type.
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// use bounded_integer::{bounded, bounded_integer};
+///
+/// bounded_integer! {
+///     #[repr(u8)]
+///     pub struct Percent { 0..=100 }
+/// }
+///
+/// const HALF: Percent = bounded!(Percent, 50);
+/// assert_eq!(HALF.get(), 50);
+/// assert_eq!(bounded!(Percent, 25 * 4), Percent::MAX);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// use bounded_integer::{bounded, bounded_integer};
+///
+/// bounded_integer! {
+///     #[repr(u8)]
+///     pub struct Percent { 0..=100 }
+/// }
+///
+/// let too_much = bounded!(Percent, 101);
+/// ```
+#[macro_export]
+macro_rules! bounded {
+    ($ty:ty, $value:expr $(,)?) => {{
+        const VALUE: $ty = <$ty>::new_const($value);
+        VALUE
+    }};
+}
+
+/// The attribute form of [`bounded_integer!`](crate::bounded_integer).
+///
+/// ```rust
+/// # #[cfg(not(feature = "serde"))] {
+/// use bounded_integer::attr::bounded_integer;
+///
+/// #[bounded_integer(-8..8)]
+/// pub struct S(i8);
+/// # }
+/// ```
+pub mod attr {
+    pub use bounded_integer_macro::bounded_integer_attr as bounded_integer;
+}
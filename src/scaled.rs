@@ -0,0 +1,12 @@
+/// How to round a value that falls between two values of a fixed-point bounded integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest value, and away from zero on ties.
+    Nearest,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+    /// Round towards zero.
+    TowardZero,
+}
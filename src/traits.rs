@@ -0,0 +1,102 @@
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+
+/// A bounded integer. This is implemented by every type generated by
+/// [`bounded_integer!`](crate::bounded_integer), allowing code to be generic over them.
+///
+/// All the items of this trait are also available as inherent items on the generated types, so
+/// it doesn't need to be imported to use them.
+pub trait BoundedInteger: Copy + Eq + Ord {
+    /// The primitive integer type the bounded integer is represented as. This is the way to name
+    /// the repr of bounded integers that had it chosen automatically.
+    type Repr: Copy + Eq + Ord + Hash + Debug + Display;
+
+    /// The smallest value that this bounded integer can contain.
+    const MIN_VALUE: Self::Repr;
+    /// The largest value that this bounded integer can contain.
+    const MAX_VALUE: Self::Repr;
+
+    /// The smallest value of the bounded integer.
+    const MIN: Self;
+    /// The largest value of the bounded integer.
+    const MAX: Self;
+
+    /// The number of values the bounded integer can contain. This is 0 if it can contain all
+    /// 2<sup>128</sup> values of a 128-bit integer.
+    const RANGE: u128;
+
+    /// Creates a bounded integer without checking the value.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be outside the valid range of values; it must not be less than
+    /// `MIN` or greater than `MAX`.
+    unsafe fn new_unchecked(n: Self::Repr) -> Self;
+
+    /// Checks whether the given value is in the range of the bounded integer.
+    fn in_range(n: Self::Repr) -> bool;
+
+    /// Creates a bounded integer if the given value is within the range [`MIN`, `MAX`].
+    ///
+    /// [`MIN`]: Self::MIN
+    /// [`MAX`]: Self::MAX
+    fn new(n: Self::Repr) -> Option<Self>;
+
+    /// Creates a bounded integer by setting the value to `MIN` or `MAX` if it is too low or too
+    /// high respectively.
+    fn new_saturating(n: Self::Repr) -> Self;
+
+    /// Creates a bounded integer by using modulo arithmetic. Values in the range won't be
+    /// changed but values outside will be wrapped around.
+    fn new_wrapping(n: Self::Repr) -> Self;
+
+    /// Gets the value of the bounded integer as a primitive type.
+    fn get(self) -> Self::Repr;
+}
+
+/// An object safe view of a bounded integer's value and bounds, with all values widened to
+/// `i128`.
+///
+/// This is implemented for every [`BoundedInteger`], and is useful for code that has to deal with
+/// many different bounded integers at once, such as generic user interfaces or validators.
+///
+/// Values of `u128` and `usize` bounded integers that are too large for an `i128` are reported as
+/// `i128::MAX`.
+pub trait DynBoundedInteger {
+    /// The smallest value that this bounded integer can contain.
+    fn min_value_i128(&self) -> i128;
+
+    /// The largest value that this bounded integer can contain.
+    fn max_value_i128(&self) -> i128;
+
+    /// The value of this bounded integer.
+    fn get_i128(&self) -> i128;
+
+    /// Checks whether the given value is in the range of the bounded integer.
+    fn in_range_i128(&self, n: i128) -> bool;
+}
+
+impl<T> DynBoundedInteger for T
+where
+    T: BoundedInteger,
+    T::Repr: TryInto<i128> + TryFrom<i128>,
+{
+    fn min_value_i128(&self) -> i128 {
+        to_i128(T::MIN_VALUE)
+    }
+    fn max_value_i128(&self) -> i128 {
+        to_i128(T::MAX_VALUE)
+    }
+    fn get_i128(&self) -> i128 {
+        to_i128(self.get())
+    }
+    fn in_range_i128(&self, n: i128) -> bool {
+        T::Repr::try_from(n).is_ok_and(T::in_range)
+    }
+}
+
+fn to_i128<R: TryInto<i128>>(n: R) -> i128 {
+    // Only positive values can fail to convert.
+    n.try_into().unwrap_or(i128::MAX)
+}